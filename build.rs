@@ -0,0 +1,755 @@
+//! Generates `Block`, its property enums and their state ids from `ref/1.20.1_blocks.json`,
+//! `Biome` from `ref/1_20_1_biomes.json` and the items from `ref/1.20.1_items.json`.
+
+use std::{collections::BTreeMap, env, fmt::Write, fs, path::Path};
+
+use serde_json::Value;
+
+const BLOCKS: &str = "ref/1.20.1_blocks.json";
+const BIOMES: &str = "ref/1_20_1_biomes.json";
+const ITEMS: &str = "ref/1.20.1_items.json";
+/// The ids of biomes sent to clients are their index in the registry codec.
+const REGISTRY_CODEC: &str = "ref/registry_codec.json";
+/// The biome of sections that do not set one.
+const DEFAULT_BIOME: &str = "plains";
+
+/// Names of the enums of properties, by their values in the order of state ids.
+/// Properties with other values are named after the property.
+const PROPERTY_ENUMS: &[(&str, &[&str])] = &[
+    ("Facing", &["north", "east", "south", "west", "up", "down"]),
+    ("HorizontalFacing", &["north", "south", "west", "east"]),
+    ("HopperFacing", &["down", "north", "south", "west", "east"]),
+    ("Face", &["floor", "wall", "ceiling"]),
+    ("Half", &["top", "bottom"]),
+    ("DoubleBlockHalf", &["upper", "lower"]),
+    ("DoorHinge", &["left", "right"]),
+    ("Axis", &["x", "y", "z"]),
+    ("SlabType", &["top", "bottom", "double"]),
+    (
+        "StairsShape",
+        &[
+            "straight",
+            "inner_left",
+            "inner_right",
+            "outer_left",
+            "outer_right",
+        ],
+    ),
+    (
+        "RailShape",
+        &[
+            "north_south",
+            "east_west",
+            "ascending_east",
+            "ascending_west",
+            "ascending_north",
+            "ascending_south",
+            "south_east",
+            "south_west",
+            "north_west",
+            "north_east",
+        ],
+    ),
+    (
+        "RedstoneRailShape",
+        &[
+            "north_south",
+            "east_west",
+            "ascending_east",
+            "ascending_west",
+            "ascending_north",
+            "ascending_south",
+        ],
+    ),
+    ("WallConnection", &["none", "low", "tall"]),
+    ("BambooLeaves", &["none", "small", "large"]),
+    (
+        "BellAttachment",
+        &["floor", "ceiling", "single_wall", "double_wall"],
+    ),
+    ("DripleafTilt", &["none", "unstable", "partial", "full"]),
+    ("BedPart", &["head", "foot"]),
+    ("SculkSensorPhase", &["inactive", "active", "cooldown"]),
+    ("ChestType", &["single", "left", "right"]),
+    ("ComparatorMode", &["compare", "subtract"]),
+    (
+        "JigsawOrientation",
+        &[
+            "down_east",
+            "down_north",
+            "down_south",
+            "down_west",
+            "up_east",
+            "up_north",
+            "up_south",
+            "up_west",
+            "west_up",
+            "east_up",
+            "north_up",
+            "south_up",
+        ],
+    ),
+    ("PistonType", &["normal", "sticky"]),
+    ("HorizontalAxis", &["x", "z"]),
+    (
+        "NoteBlockInstrument",
+        &[
+            "harp",
+            "basedrum",
+            "snare",
+            "hat",
+            "bass",
+            "flute",
+            "bell",
+            "guitar",
+            "chime",
+            "xylophone",
+            "iron_xylophone",
+            "cow_bell",
+            "didgeridoo",
+            "bit",
+            "banjo",
+            "pling",
+            "zombie",
+            "skeleton",
+            "creeper",
+            "dragon",
+            "wither_skeleton",
+            "piglin",
+            "custom_head",
+        ],
+    ),
+    ("PointedDripstoneDirection", &["up", "down"]),
+    (
+        "PointedDripstoneThickness",
+        &["tip_merge", "tip", "frustum", "middle", "base"],
+    ),
+    ("RedstoneWireConnection", &["up", "side", "none"]),
+    ("StructureBlockMode", &["save", "load", "corner", "data"]),
+];
+
+struct Property {
+    name: String,
+    /// `bool`, a bounded integer or an enum.
+    ty: String,
+    values: Vec<String>,
+}
+
+struct Block {
+    name: String,
+    variant: String,
+    /// Missing for some blocks, e.g. wall banners.
+    display_name: Option<String>,
+    min_state_id: u64,
+    num_states: u64,
+    /// Sorted by name, like vanilla.
+    properties: Vec<Property>,
+    default_state_id: u64,
+    /// Negative for blocks that cannot be broken.
+    hardness: f64,
+    solid: bool,
+    /// By state, from the first one.
+    light_opacity: Vec<u64>,
+    light_emission: Vec<u64>,
+}
+
+struct Biome {
+    name: String,
+    variant: String,
+    id: u64,
+    temperature: f64,
+    downfall: f64,
+    has_precipitation: bool,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={BLOCKS}");
+    println!("cargo:rerun-if-changed={BIOMES}");
+    println!("cargo:rerun-if-changed={REGISTRY_CODEC}");
+    println!("cargo:rerun-if-changed={ITEMS}");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let reference: BTreeMap<String, Value> =
+        serde_json::from_str(&fs::read_to_string(BLOCKS).expect("Could not read blocks"))
+            .expect("Invalid blocks");
+
+    // Every property of an enum must have the same values
+    let mut enums: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut ranges: BTreeMap<String, (u8, u8)> = BTreeMap::new();
+    let mut blocks: Vec<Block> = reference
+        .into_iter()
+        .map(|(name, block)| {
+            let properties: Vec<Property> = block["properties"]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(property, values)| {
+                    let values: Vec<String> = values
+                        .as_array()
+                        .unwrap_or_else(|| panic!("Invalid values of {name}[{property}]"))
+                        .iter()
+                        .map(|v| v.as_str().unwrap().to_string())
+                        .collect();
+                    let ty: String = property_type(property, &values);
+                    if let Some((min, max)) = int_range(&values) {
+                        ranges.insert(ty.clone(), (min, max));
+                    } else if ty != "bool" {
+                        let existing: &Vec<String> =
+                            enums.entry(ty.clone()).or_insert(values.clone());
+                        assert_eq!(
+                            existing, &values,
+                            "{name}[{property}] needs an entry in PROPERTY_ENUMS"
+                        );
+                    }
+                    Property {
+                        name: property.clone(),
+                        ty,
+                        values,
+                    }
+                })
+                .collect();
+
+            Block {
+                variant: camel_case(&name),
+                display_name: block["display_name"].as_str().map(str::to_string),
+                min_state_id: block["min_state_id"].as_u64().unwrap(),
+                num_states: block["num_states"].as_u64().unwrap(),
+                properties,
+                default_state_id: block["default_state_id"].as_u64().unwrap(),
+                hardness: block["hardness"].as_f64().unwrap(),
+                solid: block["solid"].as_bool().unwrap(),
+                light_opacity: per_state(&block["light_opacity"], &name),
+                light_emission: per_state(&block["light_emission"], &name),
+                name,
+            }
+        })
+        .collect();
+    blocks.sort_by_key(|b| b.min_state_id);
+
+    for block in &blocks {
+        let states: u64 = block
+            .properties
+            .iter()
+            .map(|p| p.values.len() as u64)
+            .product();
+        assert_eq!(
+            states, block.num_states,
+            "The properties of {} do not match its state count",
+            block.name
+        );
+        assert!(
+            (block.min_state_id..block.min_state_id + block.num_states)
+                .contains(&block.default_state_id),
+            "The default state of {} is not one of its states",
+            block.name
+        );
+        for light in [&block.light_opacity, &block.light_emission] {
+            assert!(
+                light.len() == 1 || light.len() as u64 == block.num_states,
+                "The light of {} does not match its state count",
+                block.name
+            );
+        }
+    }
+
+    let out_dir: String = env::var("OUT_DIR").unwrap();
+    fs::write(
+        Path::new(&out_dir).join("block_states.rs"),
+        generate_block_states(&enums, &ranges),
+    )
+    .unwrap();
+    fs::write(
+        Path::new(&out_dir).join("block.rs"),
+        generate_blocks(&blocks),
+    )
+    .unwrap();
+    fs::write(Path::new(&out_dir).join("item.rs"), generate_items(&blocks)).unwrap();
+    fs::write(
+        Path::new(&out_dir).join("biome.rs"),
+        generate_biomes(&read_biomes()),
+    )
+    .unwrap();
+}
+
+/// Reads the biomes in the order of their ids.
+fn read_biomes() -> Vec<Biome> {
+    let reference: BTreeMap<String, Value> =
+        serde_json::from_str(&fs::read_to_string(BIOMES).expect("Could not read biomes"))
+            .expect("Invalid biomes");
+    let registry_codec: Value = serde_json::from_str(
+        &fs::read_to_string(REGISTRY_CODEC).expect("Could not read the registry codec"),
+    )
+    .expect("Invalid registry codec");
+    let ids: BTreeMap<&str, u64> = registry_codec["minecraft:worldgen/biome"]["value"]
+        .as_array()
+        .expect("The registry codec has no biomes")
+        .iter()
+        .map(|b| (b["name"].as_str().unwrap(), b["id"].as_u64().unwrap()))
+        .collect();
+    assert_eq!(
+        ids.len(),
+        reference.len(),
+        "The registry codec does not have every biome"
+    );
+
+    let mut biomes: Vec<Biome> = reference
+        .into_iter()
+        .map(|(name, biome)| {
+            let name: String = name.strip_prefix("minecraft:").unwrap_or(&name).to_string();
+            Biome {
+                variant: camel_case(&name),
+                id: *ids
+                    .get(format!("minecraft:{name}").as_str())
+                    .unwrap_or_else(|| panic!("{name} is not in the registry codec")),
+                temperature: biome["temperature"].as_f64().unwrap(),
+                downfall: biome["downfall"].as_f64().unwrap(),
+                has_precipitation: biome["has_precipitation"].as_bool().unwrap(),
+                name,
+            }
+        })
+        .collect();
+    biomes.sort_by_key(|b| b.id);
+    for (i, biome) in biomes.iter().enumerate() {
+        assert_eq!(biome.id, i as u64, "Biome ids are not consecutive");
+    }
+    biomes
+}
+
+fn generate_biomes(biomes: &[Biome]) -> String {
+    let mut d: String = String::new();
+
+    writeln!(
+        d,
+        "#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]"
+    )
+    .unwrap();
+    writeln!(d, "#[repr(u8)]").unwrap();
+    writeln!(d, "pub enum Biome {{").unwrap();
+    for biome in biomes {
+        if biome.name == DEFAULT_BIOME {
+            writeln!(d, "    #[default]").unwrap();
+        }
+        writeln!(d, "    {} = {},", biome.variant, biome.id).unwrap();
+    }
+    writeln!(d, "}}\n").unwrap();
+
+    writeln!(d, "impl Biome {{").unwrap();
+    writeln!(d, "    pub fn from_id(id: u8) -> Option<Self> {{").unwrap();
+    writeln!(d, "        use Biome::*;").unwrap();
+    writeln!(d, "        Some(match id {{").unwrap();
+    for biome in biomes {
+        writeln!(d, "            {} => {},", biome.id, biome.variant).unwrap();
+    }
+    writeln!(d, "            _ => return None,").unwrap();
+    writeln!(d, "        }})\n    }}").unwrap();
+    writeln!(d, "}}\n").unwrap();
+
+    writeln!(d, "/// Sorted by id.").unwrap();
+    writeln!(d, "pub static BIOMES: [BiomeInfo; {}] = [", biomes.len()).unwrap();
+    for biome in biomes {
+        writeln!(
+            d,
+            "    BiomeInfo {{ name: \"{}\", temperature: {:?}, downfall: {:?}, has_precipitation: {} }},",
+            biome.name,
+            biome.temperature as f32,
+            biome.downfall as f32,
+            biome.has_precipitation
+        )
+        .unwrap();
+    }
+    writeln!(d, "];").unwrap();
+    d
+}
+
+fn generate_block_states(
+    enums: &BTreeMap<String, Vec<String>>,
+    ranges: &BTreeMap<String, (u8, u8)>,
+) -> String {
+    let mut d: String = String::new();
+    for (name, values) in enums {
+        writeln!(d, "#[derive(Debug, PartialEq, Eq, Clone, Copy)]").unwrap();
+        writeln!(d, "#[repr(u8)]").unwrap();
+        writeln!(d, "pub enum {name} {{").unwrap();
+        for value in values {
+            writeln!(d, "    {},", camel_case(value)).unwrap();
+        }
+        writeln!(d, "}}\n").unwrap();
+    }
+
+    writeln!(d, "property_values! {{").unwrap();
+    for (name, values) in enums {
+        writeln!(d, "    {name} {{").unwrap();
+        for value in values {
+            writeln!(d, "        {} => \"{value}\",", camel_case(value)).unwrap();
+        }
+        writeln!(d, "    }}").unwrap();
+    }
+    writeln!(d, "}}\n").unwrap();
+
+    for (name, (min, max)) in ranges {
+        writeln!(d, "pub type {name} = BoundedU8<{min}, {max}>;").unwrap();
+    }
+    d
+}
+
+fn generate_blocks(blocks: &[Block]) -> String {
+    let mut d: String = String::new();
+
+    let mut types: Vec<&str> = blocks
+        .iter()
+        .flat_map(|b| &b.properties)
+        .map(|p| p.ty.as_str())
+        .filter(|&ty| ty != "bool")
+        .collect();
+    types.sort_unstable();
+    types.dedup();
+    writeln!(d, "use super::{{PropertyValue, {}}};\n", types.join(", ")).unwrap();
+
+    writeln!(d, "#[derive(Debug, PartialEq, Eq, Clone, Copy)]").unwrap();
+    writeln!(d, "#[repr(u16)]").unwrap();
+    writeln!(d, "#[allow(clippy::enum_variant_names)]").unwrap();
+    writeln!(d, "pub enum Block {{").unwrap();
+    for block in blocks {
+        if block.properties.is_empty() {
+            writeln!(d, "    {} = {},", block.variant, block.min_state_id).unwrap();
+        } else {
+            writeln!(d, "    {} {{", block.variant).unwrap();
+            for property in &block.properties {
+                writeln!(d, "        {}: {},", field(&property.name), property.ty).unwrap();
+            }
+            writeln!(d, "    }} = {},", block.min_state_id).unwrap();
+        }
+    }
+    writeln!(d, "}}\n").unwrap();
+
+    writeln!(d, "impl Block {{").unwrap();
+
+    // The last property changes fastest
+    writeln!(d, "    pub fn get_state_id(&self) -> u16 {{").unwrap();
+    writeln!(d, "        use Block::*;").unwrap();
+    writeln!(d, "        match self {{").unwrap();
+    for block in blocks {
+        if block.properties.is_empty() {
+            writeln!(
+                d,
+                "            {} => {},",
+                block.variant, block.min_state_id
+            )
+            .unwrap();
+            continue;
+        }
+        let mut terms: Vec<String> = vec![block.min_state_id.to_string()];
+        for (property, multiplier) in multipliers(block) {
+            let field: String = field(&property.name);
+            terms.push(match multiplier {
+                1 => format!("{field}.index()"),
+                m => format!("{field}.index() * {m}"),
+            });
+        }
+        writeln!(
+            d,
+            "            {} {{ {} }} => {},",
+            block.variant,
+            fields(block),
+            terms.join(" + ")
+        )
+        .unwrap();
+    }
+    writeln!(d, "        }}\n    }}\n").unwrap();
+
+    writeln!(
+        d,
+        "    /// The inverse of [`Block::get_state_id`]. Returns `None` for ids past the last block state."
+    )
+    .unwrap();
+    writeln!(d, "    pub fn from_state_id(id: u16) -> Option<Self> {{").unwrap();
+    writeln!(d, "        use Block::*;").unwrap();
+    writeln!(d, "        Some(match id {{").unwrap();
+    for block in blocks {
+        if block.properties.is_empty() {
+            writeln!(
+                d,
+                "            {} => {},",
+                block.min_state_id, block.variant
+            )
+            .unwrap();
+            continue;
+        }
+        let values: Vec<String> = multipliers(block)
+            .into_iter()
+            .map(|(property, multiplier)| {
+                let count: u64 = property.values.len() as u64;
+                let index: String = if multiplier == 1 && count == block.num_states {
+                    "i".to_string()
+                } else if multiplier == 1 {
+                    format!("i % {count}")
+                } else if multiplier * count == block.num_states {
+                    format!("i / {multiplier}")
+                } else {
+                    format!("i / {multiplier} % {count}")
+                };
+                format!(
+                    "{}: PropertyValue::from_index({index})?",
+                    field(&property.name)
+                )
+            })
+            .collect();
+        writeln!(
+            d,
+            "            {}..={} => {{ let i: u16 = id - {}; {} {{ {} }} }}",
+            block.min_state_id,
+            block.min_state_id + block.num_states - 1,
+            block.min_state_id,
+            block.variant,
+            values.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(d, "            _ => return None,").unwrap();
+    writeln!(d, "        }})\n    }}\n").unwrap();
+
+    writeln!(
+        d,
+        "    /// Whether the block is in a water source, from its `waterlogged` property."
+    )
+    .unwrap();
+    writeln!(d, "    pub fn is_waterlogged(&self) -> bool {{").unwrap();
+    writeln!(d, "        use Block::*;").unwrap();
+    writeln!(d, "        match self {{").unwrap();
+    for block in blocks
+        .iter()
+        .filter(|b| b.properties.iter().any(|p| p.name == "waterlogged"))
+    {
+        writeln!(
+            d,
+            "            {} {{ waterlogged, .. }} => *waterlogged,",
+            block.variant
+        )
+        .unwrap();
+    }
+    writeln!(d, "            _ => false,").unwrap();
+    writeln!(d, "        }}\n    }}\n").unwrap();
+
+    writeln!(
+        d,
+        "    /// The vanilla block state properties of the block, sorted by name."
+    )
+    .unwrap();
+    writeln!(
+        d,
+        "    pub fn properties(&self) -> Vec<(&'static str, String)> {{"
+    )
+    .unwrap();
+    writeln!(d, "        use Block::*;").unwrap();
+    writeln!(d, "        match self {{").unwrap();
+    for block in blocks.iter().filter(|b| !b.properties.is_empty()) {
+        let values: Vec<String> = block
+            .properties
+            .iter()
+            .map(|p| format!("(\"{}\", {}.to_value())", p.name, field(&p.name)))
+            .collect();
+        writeln!(
+            d,
+            "            {} {{ {} }} => vec![{}],",
+            block.variant,
+            fields(block),
+            values.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(d, "            _ => vec![],").unwrap();
+    writeln!(d, "        }}\n    }}\n").unwrap();
+
+    writeln!(
+        d,
+        "    /// Reads a block from its vanilla name and block state properties,"
+    )
+    .unwrap();
+    writeln!(d, "    /// as found in the palettes of region files.").unwrap();
+    writeln!(
+        d,
+        "    /// Omitted properties take the value of the default state, unknown ones are ignored."
+    )
+    .unwrap();
+    writeln!(d, "    pub fn from_properties(name: &str, properties: &HashMap<String, String>) -> Result<Self> {{").unwrap();
+    writeln!(d, "        use Block::*;").unwrap();
+    writeln!(
+        d,
+        "        let name: &str = name.strip_prefix(\"minecraft:\").unwrap_or(name);"
+    )
+    .unwrap();
+    writeln!(d, "        Ok(match name {{").unwrap();
+    for block in blocks {
+        if block.properties.is_empty() {
+            writeln!(d, "            \"{}\" => {},", block.name, block.variant).unwrap();
+            continue;
+        }
+        let default: u64 = block.default_state_id - block.min_state_id;
+        let values: Vec<String> = multipliers(block)
+            .into_iter()
+            .map(|(p, multiplier)| {
+                format!(
+                    "{}: property(properties, \"{}\", {})?",
+                    field(&p.name),
+                    p.name,
+                    default / multiplier % p.values.len() as u64
+                )
+            })
+            .collect();
+        writeln!(
+            d,
+            "            \"{}\" => {} {{ {} }},",
+            block.name,
+            block.variant,
+            values.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(d, "            _ => bail!(\"Unknown block {{name}}\"),").unwrap();
+    writeln!(d, "        }})\n    }}").unwrap();
+    writeln!(d, "}}\n").unwrap();
+
+    writeln!(d, "/// Sorted by state id.").unwrap();
+    writeln!(d, "pub static BLOCKS: [BlockInfo; {}] = [", blocks.len()).unwrap();
+    for block in blocks {
+        writeln!(
+            d,
+            "    BlockInfo {{ name: \"{}\", display_name: {:?}, min_state_id: {}, num_states: {}, default_state_id: {}, hardness: {:?}, solid: {} }},",
+            block.name,
+            block.display_name,
+            block.min_state_id,
+            block.num_states,
+            block.default_state_id,
+            (block.hardness >= 0.0).then_some(block.hardness as f32),
+            block.solid
+        )
+        .unwrap();
+    }
+    writeln!(d, "];\n").unwrap();
+
+    let states: u64 = blocks.iter().map(|b| b.num_states).sum();
+    let opacity: Vec<&Vec<u64>> = blocks.iter().map(|b| &b.light_opacity).collect();
+    let emission: Vec<&Vec<u64>> = blocks.iter().map(|b| &b.light_emission).collect();
+    for (name, light) in [("LIGHT_OPACITY", opacity), ("LIGHT_EMISSION", emission)] {
+        let values: Vec<String> = blocks
+            .iter()
+            .zip(light)
+            .flat_map(|(block, light)| {
+                (0..block.num_states as usize).map(|i| light[i % light.len()].to_string())
+            })
+            .collect();
+        writeln!(d, "/// By state id.").unwrap();
+        writeln!(
+            d,
+            "static {name}: [u8; {states}] = [{}];",
+            values.join(", ")
+        )
+        .unwrap();
+    }
+    d
+}
+
+/// Items are matched to the block with the same display name.
+fn generate_items(blocks: &[Block]) -> String {
+    let reference: Vec<Value> =
+        serde_json::from_str(&fs::read_to_string(ITEMS).expect("Could not read items"))
+            .expect("Invalid items");
+    let mut items: Vec<(u64, Option<&str>)> = reference
+        .iter()
+        .map(|item| (item["id"].as_u64().unwrap(), item["name"].as_str()))
+        .collect();
+    items.sort_by_key(|&(id, _)| id);
+    for (i, &(id, _)) in items.iter().enumerate() {
+        assert_eq!(id, i as u64, "Item ids are not consecutive");
+    }
+
+    let mut d: String = String::new();
+    writeln!(d, "/// Sorted by id.").unwrap();
+    writeln!(d, "pub static ITEMS: [ItemInfo; {}] = [", items.len()).unwrap();
+    for (id, display_name) in items {
+        let block: Option<u64> = blocks
+            .iter()
+            .find(|b| display_name.is_some() && b.display_name.as_deref() == display_name)
+            .map(|b| b.default_state_id);
+        writeln!(
+            d,
+            "    ItemInfo {{ id: {id}, display_name: {display_name:?}, block_state_id: {block:?} }},"
+        )
+        .unwrap();
+    }
+    writeln!(d, "];").unwrap();
+    d
+}
+
+/// Light values are either the same for every state of a block or listed by state.
+fn per_state(value: &Value, block: &str) -> Vec<u64> {
+    match value {
+        Value::Array(values) => values.iter().map(|v| v.as_u64().unwrap()).collect(),
+        value => vec![value
+            .as_u64()
+            .unwrap_or_else(|| panic!("Invalid light of {block}"))],
+    }
+}
+
+/// The properties of a block with the factor of their index in its state ids.
+fn multipliers(block: &Block) -> Vec<(&Property, u64)> {
+    let mut multiplier: u64 = 1;
+    let mut d: Vec<(&Property, u64)> = vec![];
+    for property in block.properties.iter().rev() {
+        d.push((property, multiplier));
+        multiplier *= property.values.len() as u64;
+    }
+    d.reverse();
+    d
+}
+
+fn property_type(property: &str, values: &[String]) -> String {
+    if values == ["true", "false"] {
+        return "bool".to_string();
+    }
+    if let Some((min, max)) = int_range(values) {
+        return format!("I{min}_{max}");
+    }
+    PROPERTY_ENUMS
+        .iter()
+        .find(|(_, v)| v.iter().eq(values.iter()))
+        .map(|(name, _)| name.to_string())
+        .unwrap_or_else(|| camel_case(property))
+}
+
+/// Integer properties have consecutive values.
+fn int_range(values: &[String]) -> Option<(u8, u8)> {
+    let values: Vec<u8> = values
+        .iter()
+        .map(|v| v.parse().ok())
+        .collect::<Option<_>>()?;
+    let (&min, &max) = (values.first()?, values.last()?);
+    values.iter().copied().eq(min..=max).then_some((min, max))
+}
+
+fn fields(block: &Block) -> String {
+    block
+        .properties
+        .iter()
+        .map(|p| field(&p.name))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn field(property: &str) -> String {
+    match property {
+        "type" => "r#type".to_string(),
+        _ => property.to_string(),
+    }
+}
+
+fn camel_case(s: &str) -> String {
+    s.split('_')
+        .map(|w| {
+            let mut c: std::str::Chars = w.chars();
+            c.next()
+                .map(|f| f.to_uppercase().chain(c).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
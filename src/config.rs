@@ -5,7 +5,14 @@ use log::warn;
 use serde_derive::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::PROTOCOL_VERSION;
+use crate::{
+    server::{
+        auth::MOJANG_SESSION_SERVER,
+        types::{Dimension, Gamemode},
+        world::generator::DEFAULT_FLAT_LAYERS,
+    },
+    MINECRAFT_VERSION, PROTOCOL_VERSION,
+};
 
 pub fn get_config() -> Config {
     let mut config: Config =
@@ -25,19 +32,195 @@ pub fn get_config() -> Config {
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(default)]
 pub struct Config {
     pub port: u16,
+    /// Packets of at least this many bytes are compressed. A negative value disables compression.
+    pub compression_threshold: i32,
+    /// Whether players are verified with the session server.
+    pub online_mode: bool,
+    pub session_server: String,
+    pub connection_limits: ConnectionLimits,
+    /// Whether connections start with a PROXY protocol header, for servers behind a load balancer.
+    pub proxy_protocol: bool,
+    pub send_queue: SendQueueLimits,
+    /// How player info is forwarded by a proxy in front of the server.
+    pub forwarding: Forwarding,
+    /// The secret shared with Velocity, used to verify forwarded player info.
+    pub forwarding_secret: String,
+    /// If set, every connection is recorded to a file in this directory, to be read with `replay`.
+    pub recordings_directory: Option<String>,
+    /// If set, chunks are loaded from and saved to the region files of the world in this directory.
+    pub world_directory: Option<String>,
+    /// The dimensions of the server. Players join the first one.
+    pub worlds: Vec<WorldConfig>,
+    /// In seconds, how long chunks that no player has loaded stay in memory before they are
+    /// saved and dropped.
+    pub chunk_unload_delay: u64,
+    /// The gamemode players join in.
+    pub gamemode: Gamemode,
+    /// The names of the players allowed to use admin commands, like `/paste`.
+    pub operators: Vec<String>,
+    /// The directory schematics are pasted from.
+    pub schematics_directory: String,
+    /// Schematics pasted into the worlds at startup.
+    pub schematics: Vec<SchematicPaste>,
     pub status: Status,
 }
 impl Default for Config {
     fn default() -> Self {
         Self {
             port: 25565,
+            compression_threshold: 256,
+            online_mode: false,
+            session_server: MOJANG_SESSION_SERVER.to_string(),
+            connection_limits: ConnectionLimits::default(),
+            proxy_protocol: false,
+            send_queue: SendQueueLimits::default(),
+            recordings_directory: None,
+            world_directory: None,
+            worlds: vec![
+                WorldConfig::default(),
+                WorldConfig {
+                    name: "minecraft:the_nether".to_string(),
+                    dimension: Dimension::Nether,
+                    ..WorldConfig::default()
+                },
+                WorldConfig {
+                    name: "minecraft:the_end".to_string(),
+                    dimension: Dimension::End,
+                    generator: Generator::Flat,
+                    layers: "minecraft:bedrock,64*minecraft:end_stone".to_string(),
+                    biome: "minecraft:the_end".to_string(),
+                },
+            ],
+            chunk_unload_delay: 30,
+            gamemode: Gamemode::Creative,
+            operators: vec![],
+            schematics_directory: "schematics".to_string(),
+            schematics: vec![],
+            forwarding: Forwarding::None,
+            forwarding_secret: String::new(),
             status: Status::default(),
         }
     }
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Forwarding {
+    None,
+    /// Legacy forwarding in the Handshake's server address.
+    BungeeCord,
+    /// Modern forwarding through a Login Plugin Request.
+    Velocity,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(default)]
+pub struct WorldConfig {
+    /// The name of the dimension, like `minecraft:overworld` or `lobby:hub`.
+    pub name: String,
+    /// The type of the dimension, which sets its height.
+    pub dimension: Dimension,
+    /// How chunks that have not been saved are generated.
+    pub generator: Generator,
+    /// The layers of flat worlds from the bottom up, in the syntax of vanilla presets,
+    /// like `minecraft:bedrock,2*minecraft:dirt,minecraft:grass_block`.
+    pub layers: String,
+    /// The biome of flat worlds.
+    pub biome: String,
+}
+impl Default for WorldConfig {
+    fn default() -> Self {
+        Self {
+            name: "minecraft:overworld".to_string(),
+            dimension: Dimension::Overworld,
+            generator: Generator::Noise,
+            layers: DEFAULT_FLAT_LAYERS.to_string(),
+            biome: "minecraft:plains".to_string(),
+        }
+    }
+}
+
+/// A Sponge schematic (`.schem`) or vanilla structure (`.nbt`) to paste at startup.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SchematicPaste {
+    /// Relative to `schematics_directory`.
+    pub file: String,
+    /// The name of the world to paste it into.
+    pub world: String,
+    /// Where the lowest corner of the schematic goes.
+    pub origin: [i32; 3],
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Generator {
+    /// The configured `layers` in the configured `biome`,
+    /// by default bedrock, 123 stone, 4 dirt and grass.
+    Flat,
+    /// Hills and water from the noise seeded with the world seed.
+    Noise,
+}
+
+/// A limit of 0 means no limit.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct ConnectionLimits {
+    pub max_connections: usize,
+    pub max_connections_per_ip: usize,
+    /// In milliseconds, between connection attempts from the same IP.
+    pub min_connection_interval: u64,
+    /// Consecutive failed handshakes before an IP is banned.
+    pub max_handshake_failures: u32,
+    /// In seconds.
+    pub ban_duration: u64,
+}
+impl Default for ConnectionLimits {
+    fn default() -> Self {
+        Self {
+            max_connections: 256,
+            max_connections_per_ip: 8,
+            min_connection_interval: 250,
+            max_handshake_failures: 10,
+            ban_duration: 300,
+        }
+    }
+}
+
+/// Limits on the packets waiting to be written to each connection.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct SendQueueLimits {
+    /// No more packets are queued past this, and the client is disconnected.
+    pub capacity: usize,
+    /// Past this many packets, `slow_clients` decides what happens.
+    pub high_water_mark: usize,
+    pub slow_clients: SlowClientPolicy,
+    /// With the `defer` policy, clients are disconnected once this many packets are held back.
+    pub max_deferred: usize,
+}
+impl Default for SendQueueLimits {
+    fn default() -> Self {
+        Self {
+            capacity: 2048,
+            high_water_mark: 256,
+            slow_clients: SlowClientPolicy::Defer,
+            max_deferred: 4096,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SlowClientPolicy {
+    /// Disconnect the client as too slow.
+    Disconnect,
+    /// Hold back low-priority packets like chunks until the client catches up.
+    Defer,
+}
+
 #[derive(Default, Deserialize, Serialize, Debug, Clone)]
 #[allow(non_snake_case)]
 pub struct Status {
@@ -57,7 +240,7 @@ pub struct Version {
 impl Default for Version {
     fn default() -> Self {
         Self {
-            name: "Unnamed Server Software in Rust 1.20.1".to_string(),
+            name: format!("Unnamed Server Software in Rust {MINECRAFT_VERSION}"),
             protocol: PROTOCOL_VERSION,
         }
     }
@@ -8,7 +8,9 @@ static HEROBRINE: &str = "herobrine";
 
 mod client;
 mod config;
+mod replay;
 mod server;
+#[allow(dead_code)]
 mod testing;
 
 use std::env;
@@ -23,6 +25,7 @@ use log::{debug, LevelFilter};
 mod tests;
 
 static PROTOCOL_VERSION: u16 = 763;
+static MINECRAFT_VERSION: &str = "1.20.1";
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -53,6 +56,10 @@ async fn main() -> Result<()> {
         Some("client") => {
             client::start().await?;
         }
+        Some("replay") => {
+            let path: &String = args.get(2).expect("Usage: replay <file>");
+            replay::start(path).await?;
+        }
         Some("herobrine") => println!("{HEROBRINE}"),
         _ => panic!("Invalid arguments"),
     }
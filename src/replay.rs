@@ -0,0 +1,96 @@
+use eyre::{Context, Result};
+use log::{info, warn};
+use tokio::{fs::File, io::BufReader};
+
+use crate::{
+    server::{
+        net::{
+            packets::{IncomingPacket, OutgoingPacket},
+            recording::{read_recording_header, Direction, Record},
+        },
+        state::ConnectionState,
+    },
+    PROTOCOL_VERSION,
+};
+
+/// Pretty-prints the packets of a recording made by the server.
+pub async fn start(path: &str) -> Result<()> {
+    let mut file: BufReader<File> = BufReader::new(
+        File::open(path)
+            .await
+            .wrap_err_with(|| format!("Could not open {path}"))?,
+    );
+    let protocol_version: u16 = read_recording_header(&mut file).await?;
+    if protocol_version != PROTOCOL_VERSION {
+        warn!(
+            "{path} was recorded with protocol version {protocol_version}, packets may not decode"
+        );
+    }
+    info!("Replaying {path}");
+
+    // The state and compression are followed like a client would
+    let mut state: ConnectionState = ConnectionState::Handshake;
+    let mut compression_threshold: i32 = -1;
+
+    while let Some(Record {
+        time,
+        direction,
+        frame,
+    }) = Record::read(&mut file).await?
+    {
+        let prefix: String = format!(
+            "[{:>10.3}ms] {direction} {state}",
+            time.as_secs_f64() * 1000.0
+        );
+
+        match direction {
+            Direction::Serverbound => {
+                match IncomingPacket::decode(frame, &state, compression_threshold) {
+                    Ok(packet) => {
+                        println!("{prefix} {packet:#?}");
+                        if let IncomingPacket::Handshake { next_state, .. } = packet {
+                            state = match next_state {
+                                0x01 => ConnectionState::Status,
+                                0x02 => ConnectionState::Login,
+                                _ => state,
+                            };
+                        }
+                    }
+                    Err(e) => println!("{prefix} Could not decode packet: {e}"),
+                }
+            }
+            Direction::Clientbound => {
+                match OutgoingPacket::decode(frame, &state, compression_threshold) {
+                    Ok(packet) => {
+                        match packet {
+                            OutgoingPacket::SetCompression { threshold } => {
+                                compression_threshold = threshold
+                            }
+                            OutgoingPacket::LoginSuccess { .. } => state = ConnectionState::Play,
+                            _ => {}
+                        }
+                        println!("{prefix} {}", summarize(packet));
+                    }
+                    Err(e) => println!("{prefix} Could not decode packet: {e}"),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Pretty-prints a clientbound packet, with chunk data and the registry codec shown by their length.
+fn summarize(mut packet: OutgoingPacket) -> String {
+    let (field, len): (&str, usize) = match &mut packet {
+        OutgoingPacket::ChunkDataAndUpdateLight { data, .. } => {
+            ("data", std::mem::take(data).len())
+        }
+        OutgoingPacket::LoginPlay(data) => (
+            "registry_codec",
+            std::mem::take(&mut data.registry_codec).len(),
+        ),
+        _ => return format!("{packet:#?}"),
+    };
+    format!("{packet:#?}").replacen(&format!("{field}: []"), &format!("{field}: {len} bytes"), 1)
+}
@@ -0,0 +1,201 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use eyre::{ensure, eyre, Context, Result};
+use rand::{random, thread_rng};
+use rsa::{pkcs8::EncodePublicKey, Pkcs1v15Encrypt, RsaPrivateKey};
+use serde_derive::Deserialize;
+use sha1::{Digest, Sha1};
+
+use super::types::{Property, Uuid};
+
+pub const MOJANG_SESSION_SERVER: &str =
+    "https://sessionserver.mojang.com/session/minecraft/hasJoined";
+
+#[derive(Clone, Debug)]
+pub struct GameProfile {
+    pub uuid: Uuid,
+    pub name: String,
+    pub properties: Vec<Property>,
+}
+impl GameProfile {
+    pub fn offline(name: String) -> Self {
+        Self {
+            uuid: Uuid::offline(name.clone()),
+            name,
+            properties: vec![],
+        }
+    }
+}
+
+/// The result of verifying a player with the session server.
+pub struct WrappedGameProfile {
+    pub conn_id: i32,
+    pub profile: Result<GameProfile>,
+}
+
+/// Checks whether a player has joined the server through the session server.
+pub trait SessionVerifier: Send + Sync {
+    /// Returns `None` if the player has not joined.
+    #[allow(clippy::type_complexity)]
+    fn has_joined<'a>(
+        &'a self,
+        username: &'a str,
+        server_hash: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<GameProfile>>> + Send + 'a>>;
+}
+
+/// Verifies players through Mojang's session server, or anything that speaks its protocol.
+pub struct MojangSessionVerifier {
+    pub url: String,
+}
+impl SessionVerifier for MojangSessionVerifier {
+    fn has_joined<'a>(
+        &'a self,
+        username: &'a str,
+        server_hash: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<GameProfile>>> + Send + 'a>> {
+        let url: String = self.url.clone();
+        let username: String = username.to_string();
+        let server_hash: String = server_hash.to_string();
+
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || {
+                let response: ureq::Response = ureq::get(&url)
+                    .query("username", &username)
+                    .query("serverId", &server_hash)
+                    .call()
+                    .wrap_err("Session server request failed")?;
+                if response.status() == 204 {
+                    return Ok(None);
+                }
+
+                let profile: MojangProfile = response
+                    .into_json()
+                    .wrap_err("Invalid session server response")?;
+                Ok(Some(GameProfile {
+                    uuid: Uuid(u128::from_str_radix(&profile.id, 16).wrap_err("Invalid UUID")?),
+                    name: profile.name,
+                    properties: profile
+                        .properties
+                        .into_iter()
+                        .map(|p| (p.name, p.value, p.signature))
+                        .collect(),
+                }))
+            })
+            .await?
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct MojangProfile {
+    id: String,
+    name: String,
+    #[serde(default)]
+    properties: Vec<MojangProperty>,
+}
+
+#[derive(Deserialize)]
+struct MojangProperty {
+    name: String,
+    value: String,
+    signature: Option<String>,
+}
+
+/// Holds the server key pair and verifies players in online mode.
+pub struct Authenticator {
+    private_key: RsaPrivateKey,
+    /// The public key in DER format, as sent in Encryption Request.
+    pub public_key: Vec<u8>,
+    verifier: Arc<dyn SessionVerifier>,
+}
+impl Authenticator {
+    pub fn new(verifier: Arc<dyn SessionVerifier>) -> Result<Self> {
+        let private_key: RsaPrivateKey = RsaPrivateKey::new(&mut thread_rng(), 1024)?;
+        let public_key: Vec<u8> = private_key
+            .to_public_key()
+            .to_public_key_der()
+            .map_err(|e| eyre!("Could not encode public key: {e}"))?
+            .into_vec();
+
+        Ok(Self {
+            private_key,
+            public_key,
+            verifier,
+        })
+    }
+
+    pub fn gen_verify_token() -> [u8; 4] {
+        random()
+    }
+
+    /// Decrypts the contents of Encryption Response and checks the verify token.
+    pub fn decrypt_shared_secret(
+        &self,
+        shared_secret: &[u8],
+        verify_token: &[u8],
+        expected_verify_token: &[u8],
+    ) -> Result<[u8; 16]> {
+        let verify_token: Vec<u8> = self
+            .private_key
+            .decrypt(Pkcs1v15Encrypt, verify_token)
+            .wrap_err("Could not decrypt verify token")?;
+        ensure!(
+            verify_token == expected_verify_token,
+            "Invalid verify token"
+        );
+
+        self.private_key
+            .decrypt(Pkcs1v15Encrypt, shared_secret)
+            .wrap_err("Could not decrypt shared secret")?
+            .try_into()
+            .map_err(|_| eyre!("Invalid shared secret length"))
+    }
+
+    /// Returns a future that can be spawned, so that the tick loop is not blocked.
+    pub fn verify(
+        &self,
+        username: String,
+        shared_secret: [u8; 16],
+    ) -> impl Future<Output = Result<GameProfile>> + Send + 'static {
+        let verifier: Arc<dyn SessionVerifier> = self.verifier.clone();
+        let server_hash: String = server_hash("", &shared_secret, &self.public_key);
+
+        async move {
+            verifier
+                .has_joined(&username, &server_hash)
+                .await?
+                .ok_or_else(|| eyre!("{username} has not joined through the session server"))
+        }
+    }
+}
+
+/// Minecraft's SHA-1 digest, printed as a signed hexadecimal number.
+pub fn server_hash(server_id: &str, shared_secret: &[u8], public_key: &[u8]) -> String {
+    let mut hash: [u8; 20] = Sha1::new()
+        .chain_update(server_id.as_bytes())
+        .chain_update(shared_secret)
+        .chain_update(public_key)
+        .finalize()
+        .into();
+
+    let negative: bool = hash[0] & 0x80 != 0;
+    if negative {
+        // Two's complement
+        let mut carry: bool = true;
+        for byte in hash.iter_mut().rev() {
+            *byte = !*byte;
+            if carry {
+                (*byte, carry) = byte.overflowing_add(1);
+            }
+        }
+    }
+
+    let digits: String = hash.iter().map(|b| format!("{b:02x}")).collect();
+    let digits: &str = digits.trim_start_matches('0');
+    format!(
+        "{}{}",
+        if negative { "-" } else { "" },
+        if digits.is_empty() { "0" } else { digits }
+    )
+}
@@ -0,0 +1,20 @@
+use std::collections::BTreeSet;
+
+/// Hands out entity IDs, reusing the ones that have been freed.
+#[derive(Default)]
+pub struct EntityIds {
+    next: i32,
+    free: BTreeSet<i32>,
+}
+impl EntityIds {
+    pub fn allocate(&mut self) -> i32 {
+        self.free.pop_first().unwrap_or_else(|| {
+            self.next += 1;
+            self.next - 1
+        })
+    }
+
+    pub fn free(&mut self, entity_id: i32) {
+        self.free.insert(entity_id);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{collections::VecDeque, net::IpAddr};
+
+use eyre::{bail, ensure, eyre, Context, Result};
+use hmac::{Hmac, Mac};
+use serde_derive::Deserialize;
+use sha2::Sha256;
+
+use super::{
+    auth::GameProfile,
+    types::{Property, ReadString, ReadVarInt, Uuid},
+    util::ReadExt,
+};
+
+/// The channel of the Login Plugin Request sent to Velocity.
+pub const VELOCITY_CHANNEL: &str = "velocity:player_info";
+/// The version of Velocity's modern forwarding that is requested. Version 1 carries no chat keys.
+pub const VELOCITY_FORWARDING_VERSION: u8 = 1;
+const VELOCITY_SIGNATURE_LENGTH: usize = 32;
+
+/// Player info forwarded by a proxy, which replaces the offline-mode defaults.
+#[derive(Clone, Debug)]
+pub struct ForwardedPlayer {
+    pub address: IpAddr,
+    pub uuid: Uuid,
+    pub properties: Vec<Property>,
+}
+impl ForwardedPlayer {
+    pub fn into_profile(self, name: String) -> GameProfile {
+        GameProfile {
+            uuid: self.uuid,
+            name,
+            properties: self.properties,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BungeeCordProperty {
+    name: String,
+    value: String,
+    signature: Option<String>,
+}
+
+/// Parses the `server_address` of a Handshake forwarded by BungeeCord,
+/// which is `host\0client address\0UUID[\0properties as JSON]`.
+pub fn parse_bungeecord(server_address: &str) -> Result<ForwardedPlayer> {
+    let fields: Vec<&str> = server_address.split('\0').collect();
+    let (address, uuid, properties) = match fields[..] {
+        [_, address, uuid] => (address, uuid, None),
+        [_, address, uuid, properties] => (address, uuid, Some(properties)),
+        _ => bail!("Handshake was not forwarded by BungeeCord"),
+    };
+
+    let properties: Vec<BungeeCordProperty> = match properties {
+        Some(properties) => serde_json::from_str(properties).wrap_err("Invalid properties")?,
+        None => vec![],
+    };
+    Ok(ForwardedPlayer {
+        address: address.parse().wrap_err("Invalid address")?,
+        uuid: Uuid(u128::from_str_radix(uuid, 16).wrap_err("Invalid UUID")?),
+        properties: properties
+            .into_iter()
+            .map(|p| (p.name, p.value, p.signature))
+            .collect(),
+    })
+}
+
+/// Verifies and parses the data of a Login Plugin Response sent by Velocity.
+/// Returns the forwarded player and their username.
+pub fn parse_velocity(data: &[u8], secret: &[u8]) -> Result<(ForwardedPlayer, String)> {
+    ensure!(
+        data.len() > VELOCITY_SIGNATURE_LENGTH,
+        "Forwarded data is too short"
+    );
+    let (signature, data) = data.split_at(VELOCITY_SIGNATURE_LENGTH);
+
+    let mut mac: Hmac<Sha256> =
+        Hmac::new_from_slice(secret).map_err(|e| eyre!("Invalid secret: {e}"))?;
+    mac.update(data);
+    mac.verify_slice(signature)
+        .map_err(|_| eyre!("Invalid signature"))?;
+
+    let mut buf: VecDeque<u8> = VecDeque::from(data.to_vec());
+    let version: i32 = buf.read_varint()?;
+    ensure!(
+        version >= VELOCITY_FORWARDING_VERSION as i32,
+        "Unsupported forwarding version {version}"
+    );
+    let address: IpAddr = buf.read_string()?.parse().wrap_err("Invalid address")?;
+    let uuid: Uuid = Uuid(buf.read_u128()?);
+    let name: String = buf.read_string()?;
+
+    let property_count: i32 = buf.read_varint()?;
+    let mut properties: Vec<Property> = Vec::with_capacity(property_count.clamp(0, 16) as usize);
+    for _ in 0..property_count {
+        let name: String = buf.read_string()?;
+        let value: String = buf.read_string()?;
+        let signature: Option<String> = if buf.read_u8()? != 0 {
+            Some(buf.read_string()?)
+        } else {
+            None
+        };
+        properties.push((name, value, signature));
+    }
+
+    Ok((
+        ForwardedPlayer {
+            address,
+            uuid,
+            properties,
+        },
+        name,
+    ))
+}
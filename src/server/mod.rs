@@ -1,11 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
 use std::{fs, time::Duration};
 
 use bracket_noise::prelude::{FastNoise, NoiseType};
-use eyre::{eyre, Context, Result};
+use eyre::{ensure, eyre, Context, Result};
 use fastnbt::nbt;
 use lazy_static::{initialize, lazy_static};
 use log::{debug, info};
+use serde_json::json;
 use sha256::digest;
 
 use tokio::sync::mpsc::error::TryRecvError;
@@ -13,48 +16,76 @@ use tokio::time::{interval, Instant, Interval};
 use tokio::{
     self,
     net::TcpListener,
-    sync::mpsc::{UnboundedReceiver, UnboundedSender},
+    sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
 };
 use tokio_util::bytes::Buf;
 
-use crate::config::{get_config, Config};
+use crate::config::{get_config, Config, Forwarding, Generator};
 use crate::server::types::Position;
-use crate::server::util::{get_chunk, normalize_yaw};
+use crate::server::util::{normalize_yaw, spiral_chunks};
+use crate::{MINECRAFT_VERSION, PROTOCOL_VERSION};
 
+use auth::{Authenticator, GameProfile, MojangSessionVerifier, WrappedGameProfile};
+use entity_ids::EntityIds;
+use forwarding::{parse_bungeecord, parse_velocity, VELOCITY_CHANNEL, VELOCITY_FORWARDING_VERSION};
 use net::{
-    connection_manager, Connection, IncomingPacket, OutgoingPacket, WrappedConnectionState,
-    WrappedIncomingPacket, WrappedOutgoingPacket,
+    connection_manager, Connection, IncomingPacket, LoginPlayData, OutgoingPacket, RespawnData,
+    WrappedConnection, WrappedConnectionState, WrappedIncomingPacket, WrappedOutgoingPacket,
 };
 use state::ConnectionState;
-use types::{Chunk, Gamemode, PlayerInfoUpdateActions, Uuid};
+use types::{Block, DiggingStatus, Gamemode, ItemInfo, PlayerInfoUpdateActions, Property, Uuid};
+use world::{
+    generator::{FlatGenerator, NoiseGenerator, WorldGenerator},
+    schematic::Schematic,
+    World,
+};
 
+pub mod auth;
+pub mod entity_ids;
+pub mod forwarding;
 pub mod net;
 pub mod state;
 #[allow(dead_code)]
 pub mod types;
+#[allow(dead_code)]
 pub mod util;
+pub mod world;
 
 pub const SEED: i64 = 0;
 pub const VIEW_DISTANCE: i32 = 16;
+/// The number of chunks a player sees.
+pub const VIEW_CHUNKS: usize = ((VIEW_DISTANCE * 2 + 1) * (VIEW_DISTANCE * 2 + 1)) as usize;
 pub const SIMULATION_DISTANCE: i32 = 0; // Technically the truth
 pub const IS_HARDCORE: bool = false;
 pub const REDUCED_DEBUG_INFO: bool = false;
 pub const ENABLE_RESPAWN_SCREEN: bool = true;
 pub const IS_DEBUG: bool = false;
-pub const IS_FLAT: bool = true;
 pub const DEFAULT_SPAWN_POSITION: (f64, f64, f64) = (0.5, 65.0, 0.5);
+/// The slot of the first hotbar item in the player's inventory window.
+pub const HOTBAR_SLOT: i16 = 36;
+pub const OFFHAND_SLOT: i16 = 45;
+/// The share of the break time that survival players have to dig for, as vanilla allows for lag.
+pub const BREAK_TIME_TOLERANCE: f32 = 0.7;
+/// The size of a player's bounding box while standing.
+pub const PLAYER_WIDTH: f64 = 0.6;
+pub const PLAYER_HEIGHT: f64 = 1.8;
+pub const PLAYER_EYE_HEIGHT: f64 = 1.62;
+/// How far from their eyes players can break and place blocks, like vanilla.
+pub const MAX_REACH: f64 = 6.0;
 
 lazy_static! {
     pub static ref REGISTRY_CODEC: Vec<u8> = fs::read("registry_codec.nbt").unwrap();
-    pub static ref NOISE: FastNoise = {
-        let mut noise: FastNoise = FastNoise::seeded(SEED as u64);
-        noise.set_noise_type(NoiseType::PerlinFractal);
-        noise.set_frequency(0.005);
-        noise
-    };
     pub static ref HASHED_SEED: i64 = digest(&SEED.to_be_bytes()).as_bytes().get_i64();
 }
 
+/// The noise that terrain is shaped by, the same for the same seed.
+pub fn seeded_noise(seed: i64) -> FastNoise {
+    let mut noise: FastNoise = FastNoise::seeded(seed as u64);
+    noise.set_noise_type(NoiseType::PerlinFractal);
+    noise.set_frequency(0.005);
+    noise
+}
+
 // #[allow(clippy::enum_variant_names)]
 // #[derive(Clone, Copy, Debug)]
 // enum Event {
@@ -65,29 +96,70 @@ lazy_static! {
 
 #[derive(Clone, Debug)]
 struct Player {
+    pub entity_id: i32,
     pub name: String,
     pub uuid: Uuid,
+    pub properties: Vec<Property>,
     pub x: f64,
     pub y: f64,
     pub z: f64,
     pub yaw: f32,
     pub pitch: f32,
     pub on_ground: bool,
+    /// The name of the world the player is in.
+    pub world: String,
+    /// The chunks of its world that have been sent to the player.
+    pub loaded_chunks: HashSet<(i32, i32)>,
+    pub gamemode: Gamemode,
+    /// The hotbar slot being held, from 0 to 8.
+    pub selected_slot: i16,
+    /// The ids of the items in the player's inventory, by their slot in the inventory window.
+    pub inventory: HashMap<i16, i32>,
+    /// The block being broken in survival, and when it can be broken at the earliest.
+    pub digging: Option<(Position, Instant)>,
 }
 impl Player {
-    pub fn new(name: String) -> Self {
+    pub fn new(entity_id: i32, profile: GameProfile, gamemode: Gamemode, world: String) -> Self {
         Self {
-            name: name.clone(),
-            uuid: Uuid::offline(name),
+            entity_id,
+            name: profile.name,
+            uuid: profile.uuid,
+            properties: profile.properties,
             x: DEFAULT_SPAWN_POSITION.0,
             y: DEFAULT_SPAWN_POSITION.1,
             z: DEFAULT_SPAWN_POSITION.2,
             yaw: 0.0,
             pitch: 0.0,
             on_ground: true,
+            world,
+            loaded_chunks: HashSet::new(),
+            gamemode,
+            selected_slot: 0,
+            inventory: HashMap::new(),
+            digging: None,
         }
     }
 
+    /// Whether the player's bounding box overlaps a block.
+    pub fn intersects(&self, location: Position) -> bool {
+        let (x, y, z): (f64, f64, f64) = (location.x as f64, location.y as f64, location.z as f64);
+        self.x + PLAYER_WIDTH / 2.0 > x
+            && self.x - PLAYER_WIDTH / 2.0 < x + 1.0
+            && self.y + PLAYER_HEIGHT > y
+            && self.y < y + 1.0
+            && self.z + PLAYER_WIDTH / 2.0 > z
+            && self.z - PLAYER_WIDTH / 2.0 < z + 1.0
+    }
+
+    /// Whether a block is within reach, in a chunk that has been sent to the player.
+    pub fn can_reach(&self, location: Position) -> bool {
+        let chunk: (i32, i32) = (location.x.div_euclid(16), location.z.div_euclid(16));
+        let dx: f64 = location.x as f64 + 0.5 - self.x;
+        let dy: f64 = location.y as f64 + 0.5 - (self.y + PLAYER_EYE_HEIGHT);
+        let dz: f64 = location.z as f64 + 0.5 - self.z;
+        self.loaded_chunks.contains(&chunk) && dx * dx + dy * dy + dz * dz <= MAX_REACH * MAX_REACH
+    }
+
     pub fn get_block(&self) -> (i32, i32, i32) {
         (
             self.x.floor() as i32,
@@ -112,10 +184,24 @@ pub async fn start() -> Result<()> {
     let start: Instant = Instant::now();
 
     initialize(&REGISTRY_CODEC);
-    initialize(&NOISE);
     initialize(&HASHED_SEED);
 
     let config: Config = get_config();
+    ensure!(
+        (1..=config.send_queue.capacity).contains(&config.send_queue.high_water_mark),
+        "send_queue.high_water_mark must be between 1 and send_queue.capacity"
+    );
+    ensure!(
+        config.forwarding != Forwarding::Velocity || !config.forwarding_secret.is_empty(),
+        "forwarding_secret must be set to use Velocity forwarding"
+    );
+    let authenticator: Option<Authenticator> = if config.online_mode {
+        Some(Authenticator::new(Arc::new(MojangSessionVerifier {
+            url: config.session_server.clone(),
+        }))?)
+    } else {
+        None
+    };
     let listener: TcpListener = TcpListener::bind(format!("127.0.0.1:{}", config.port))
         .await
         .wrap_err_with(|| format!("Could not start server on port {}", config.port))?;
@@ -130,12 +216,74 @@ pub async fn start() -> Result<()> {
     // let mut entity_ids: Vec<i32> = vec![];
     // let mut player_data: PlayerData = HashMap::new();
 
-    let (state_sender, incoming_packet_receiver, outgoing_packet_sender) =
-        connection_manager::init(listener);
+    let (connection_receiver, state_sender, incoming_packet_receiver, outgoing_packet_sender) =
+        connection_manager::init(
+            listener,
+            config.connection_limits,
+            config.proxy_protocol,
+            config.forwarding != Forwarding::None,
+            config.send_queue,
+            config.recordings_directory.as_ref().map(PathBuf::from),
+        );
+
+    ensure!(
+        !config.worlds.is_empty(),
+        "At least one world must be configured"
+    );
+    let mut worlds: HashMap<String, World> = HashMap::new();
+    for world_config in &config.worlds {
+        let generator: Box<dyn WorldGenerator> = match world_config.generator {
+            Generator::Flat => Box::new(
+                world_config
+                    .biome
+                    .parse()
+                    .and_then(|biome| FlatGenerator::new(&world_config.layers, biome))
+                    .wrap_err_with(|| format!("Invalid flat world {}", world_config.name))?,
+            ),
+            Generator::Noise => Box::new(NoiseGenerator::default()),
+        };
+        let world: World = match &config.world_directory {
+            Some(directory) => World::open(
+                Path::new(directory),
+                &world_config.name,
+                world_config.dimension,
+                generator,
+            )?,
+            None => World::new(&world_config.name, world_config.dimension, generator),
+        };
+        ensure!(
+            worlds.insert(world_config.name.clone(), world).is_none(),
+            "World {} is configured twice",
+            world_config.name
+        );
+    }
+
+    for paste in &config.schematics {
+        let schematic: Schematic =
+            Schematic::load(&Path::new(&config.schematics_directory).join(&paste.file))?;
+        let world: &mut World = worlds.get_mut(&paste.world).ok_or_else(|| {
+            eyre!(
+                "Schematic {} is pasted into {}, which is not a world",
+                paste.file,
+                paste.world
+            )
+        })?;
+        let [x, y, z] = paste.origin;
+        let changed: Vec<(i32, i32)> = world.paste(&schematic, (x, y, z));
+        info!(
+            "Pasted {} into {} at {x} {y} {z}, changing {} chunks",
+            paste.file,
+            paste.world,
+            changed.len()
+        );
+    }
 
     info!("Done ({:?})!", start.elapsed());
     run(
         config,
+        authenticator,
+        worlds,
+        connection_receiver,
         state_sender,
         incoming_packet_receiver,
         outgoing_packet_sender,
@@ -147,6 +295,9 @@ pub async fn start() -> Result<()> {
 
 async fn run(
     mut config: Config,
+    authenticator: Option<Authenticator>,
+    mut worlds: HashMap<String, World>,
+    mut connection_receiver: UnboundedReceiver<WrappedConnection>,
     state_sender: UnboundedSender<WrappedConnectionState>,
     mut packet_receiver: UnboundedReceiver<WrappedIncomingPacket>,
     packet_sender: UnboundedSender<WrappedOutgoingPacket>,
@@ -157,25 +308,96 @@ async fn run(
     let mut connections: HashMap<i32, Connection> = HashMap::new(); // Currently active connections
     let mut drop_connections: Vec<i32> = vec![]; // Connections to drop
     let mut players: HashMap<i32, Player> = HashMap::new(); // Connected players
-    let mut chunks: HashMap<(i32, i32), Chunk> = HashMap::new(); // Chunk data
+    let mut entity_ids: EntityIds = EntityIds::default(); // Entity IDs of players
+    let (profile_sender, mut profile_receiver) = unbounded_channel(); // Players verified by the session server
 
     loop {
         tick.tick().await;
 
+        // Register new connections
+        while let Ok(WrappedConnection {
+            conn_id,
+            address,
+            send_queue,
+            throttle,
+        }) = connection_receiver.try_recv()
+        {
+            connections.insert(conn_id, Connection::new(address, send_queue, throttle));
+        }
+
         // Handle incoming packets
         handle_packets(
             &mut config,
+            authenticator.as_ref(),
+            &profile_sender,
             &state_sender,
             &mut packet_receiver,
             &packet_sender,
             &mut connections,
             &mut drop_connections,
             &mut players,
-            &mut chunks,
+            &mut worlds,
+            &mut entity_ids,
         )?;
 
+        // Send the chunks that finished loading to the players waiting for them
+        for world in worlds.values_mut() {
+            if world.receive_chunks().is_empty() {
+                continue;
+            }
+            // Chunks changed by workers are sent again once they are encoded
+            let changed: Vec<(i32, i32)> = world.take_changed();
+            for (&conn_id, player) in players.iter_mut() {
+                if player.world != world.name() {
+                    continue;
+                }
+                for &(x, z) in &changed {
+                    if player.loaded_chunks.remove(&(x, z)) {
+                        world.remove_viewer(x, z);
+                    }
+                }
+                if player.loaded_chunks.len() < VIEW_CHUNKS {
+                    send_ready_chunks(conn_id, player, world, &packet_sender)?;
+                }
+            }
+        }
+
+        // Finish logins verified by the session server
+        while let Ok(WrappedGameProfile { conn_id, profile }) = profile_receiver.try_recv() {
+            let Some(connection) = connections.get_mut(&conn_id) else {
+                continue;
+            };
+
+            match profile {
+                Ok(profile) => join(
+                    conn_id,
+                    profile,
+                    &mut config,
+                    &state_sender,
+                    &packet_sender,
+                    connection,
+                    &mut players,
+                    &mut worlds,
+                    &mut entity_ids,
+                )?,
+                Err(e) => {
+                    info!(
+                        "Failed to verify connection from {}: {e}",
+                        connection.address
+                    );
+                    packet_sender.send(WrappedOutgoingPacket {
+                        conn_id,
+                        packet: OutgoingPacket::DisconnectLogin {
+                            reason: "{\"text\":\"Failed to verify username!\"}".to_string(),
+                        },
+                    })?;
+                    drop_connections.push(conn_id);
+                }
+            }
+        }
+
         // Send keep-alives
-        if tick_count % 20 == 0 {
+        if tick_count.is_multiple_of(20) {
             for (&conn_id, connection) in &connections {
                 if connection.state == ConnectionState::Play {
                     packet_sender.send(WrappedOutgoingPacket {
@@ -186,9 +408,40 @@ async fn run(
             }
         }
 
+        // Save the chunks that changed every 5 minutes, like vanilla
+        if tick_count.is_multiple_of(6000) {
+            for world in worlds.values_mut() {
+                world.save_chunks();
+            }
+        }
+
+        // Save and drop the chunks that are no longer needed
+        if tick_count.is_multiple_of(20) {
+            for world in worlds.values_mut() {
+                let unloaded: usize =
+                    world.unload_chunks(Duration::from_secs(config.chunk_unload_delay));
+                if unloaded > 0 {
+                    debug!(
+                        "Unloaded {unloaded} chunks of {}, {} still loaded",
+                        world.name(),
+                        world.loaded_chunk_count()
+                    );
+                }
+            }
+        }
+
         // Check timeouts
         for (&conn_id, connection) in &connections {
             if connection.last_timeout.elapsed() > Duration::from_secs(5) {
+                kick(conn_id, connection, "Timed out", &packet_sender)?;
+                drop_connections.push(conn_id);
+            } else if connection.send_queue.is_too_slow() {
+                info!(
+                    "Connection from {} is too slow ({} packets queued)",
+                    connection.address,
+                    connection.send_queue.depth()
+                );
+                kick(conn_id, connection, "Too slow!", &packet_sender)?;
                 drop_connections.push(conn_id);
             }
         }
@@ -204,6 +457,34 @@ async fn run(
                 packet: OutgoingPacket::Disconnect,
             })?;
             connections.remove(&conn_id);
+
+            // Remove the player for everyone else
+            if let Some(player) = players.remove(&conn_id) {
+                info!("{} left the game", player.name);
+                config.status.players.online -= 1;
+                entity_ids.free(player.entity_id);
+                let world: &mut World = worlds.get_mut(&player.world).unwrap();
+                for &(x, z) in &player.loaded_chunks {
+                    world.remove_viewer(x, z);
+                }
+
+                for (&id, p) in &players {
+                    if p.world == player.world {
+                        packet_sender.send(WrappedOutgoingPacket {
+                            conn_id: id,
+                            packet: OutgoingPacket::RemoveEntities {
+                                entity_ids: vec![player.entity_id],
+                            },
+                        })?;
+                    }
+                    packet_sender.send(WrappedOutgoingPacket {
+                        conn_id: id,
+                        packet: OutgoingPacket::PlayerInfoRemove {
+                            players: vec![player.uuid],
+                        },
+                    })?;
+                }
+            }
         }
         drop_connections.clear();
 
@@ -211,15 +492,44 @@ async fn run(
     }
 }
 
+/// Sends a Disconnect packet with a reason, if the state has one.
+/// The connection still has to be dropped.
+fn kick(
+    conn_id: i32,
+    connection: &Connection,
+    reason: &str,
+    packet_sender: &UnboundedSender<WrappedOutgoingPacket>,
+) -> Result<()> {
+    let reason: String = json!({ "text": reason }).to_string();
+    let packet: OutgoingPacket = match connection.state {
+        ConnectionState::Login => OutgoingPacket::DisconnectLogin { reason },
+        ConnectionState::Play => OutgoingPacket::DisconnectPlay { reason },
+        _ => return Ok(()),
+    };
+    packet_sender.send(WrappedOutgoingPacket { conn_id, packet })?;
+
+    Ok(())
+}
+
+/// The reason clients on another protocol version are disconnected with, if they are.
+pub fn protocol_mismatch(protocol_version: i32) -> Option<String> {
+    (protocol_version != PROTOCOL_VERSION as i32)
+        .then(|| format!("Outdated client/server, please use {MINECRAFT_VERSION}"))
+}
+
+#[allow(clippy::too_many_arguments)]
 fn handle_packets(
     config: &mut Config,
+    authenticator: Option<&Authenticator>,
+    profile_sender: &UnboundedSender<WrappedGameProfile>,
     state_sender: &UnboundedSender<WrappedConnectionState>,
     packet_receiver: &mut UnboundedReceiver<WrappedIncomingPacket>,
     packet_sender: &UnboundedSender<WrappedOutgoingPacket>,
     connections: &mut HashMap<i32, Connection>,
     drop_connections: &mut Vec<i32>,
     players: &mut HashMap<i32, Player>,
-    chunks: &mut HashMap<(i32, i32), Chunk>,
+    worlds: &mut HashMap<String, World>,
+    entity_ids: &mut EntityIds,
 ) -> Result<()> {
     loop {
         match packet_receiver.try_recv() {
@@ -230,10 +540,10 @@ fn handle_packets(
                 };
                 let send_packet = |p: OutgoingPacket| send_packet_to(conn_id, p);
 
-                if connections.get(&conn_id).is_none() {
-                    connections.insert(conn_id, Connection::new());
-                }
-                let connection: &mut Connection = connections.get_mut(&conn_id).unwrap();
+                // Ignore packets from dropped connections
+                let Some(connection) = connections.get_mut(&conn_id) else {
+                    continue;
+                };
 
                 // Verify state (probably unnecessary)
                 if connection.state != packet.sent_state() {
@@ -244,8 +554,8 @@ fn handle_packets(
                 use IncomingPacket::*;
                 match packet {
                     Handshake {
-                        protocol_version: _,
-                        server_address: _,
+                        protocol_version,
+                        server_address,
                         server_port: _,
                         next_state,
                     } => {
@@ -265,6 +575,71 @@ fn handle_packets(
 
                         // Update state
                         state_sender.send(WrappedConnectionState { conn_id, state })?;
+
+                        // Other versions cannot parse what is sent after Login.
+                        // Status is answered anyway, so that the server list shows the mismatch.
+                        let mismatch: Option<String> = protocol_mismatch(protocol_version);
+                        if let (ConnectionState::Login, Some(reason)) = (state, mismatch) {
+                            info!(
+                                "Connection from {} uses protocol version {protocol_version}",
+                                connection.address
+                            );
+                            kick(conn_id, connection, &reason, packet_sender)?;
+                            drop_connections.push(conn_id);
+                            continue;
+                        }
+
+                        // BungeeCord forwards player info in the server address
+                        if state == ConnectionState::Login
+                            && config.forwarding == Forwarding::BungeeCord
+                        {
+                            match parse_bungeecord(&server_address) {
+                                Ok(forwarded) => {
+                                    match connection.set_forwarded_ip(conn_id, forwarded.address) {
+                                        Ok(()) => connection.forwarded = Some(forwarded),
+                                        Err(rejection) => {
+                                            info!(
+                                                "Rejected forwarded connection from {}: {rejection}",
+                                                forwarded.address
+                                            );
+                                            drop_connections.push(conn_id);
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    info!(
+                                        "Connection from {} was not forwarded: {e}",
+                                        connection.address
+                                    );
+                                    send_packet(OutgoingPacket::DisconnectLogin {
+                                        reason: "{\"text\":\"Please enable IP forwarding in BungeeCord!\"}"
+                                            .to_string(),
+                                    })?;
+                                    drop_connections.push(conn_id);
+                                }
+                            }
+                        }
+                    }
+                    LegacyServerListPing => {
+                        connection.state = ConnectionState::Status;
+                        state_sender.send(WrappedConnectionState {
+                            conn_id,
+                            state: connection.state,
+                        })?;
+
+                        // Send status in the pre-1.7 format and close the connection
+                        packet_sender.send(WrappedOutgoingPacket {
+                            conn_id,
+                            packet: OutgoingPacket::LegacyServerListPingResponse {
+                                protocol_version: config.status.version.protocol as i32,
+                                server_version: config.status.version.name.clone(),
+                                motd: config.status.description.text.clone(),
+                                online: config.status.players.online,
+                                max: config.status.players.max,
+                            },
+                        })?;
+
+                        drop_connections.push(conn_id);
                     }
                     StatusRequest => {
                         connection.last_timeout = Instant::now();
@@ -287,190 +662,170 @@ fn handle_packets(
                         drop_connections.push(conn_id);
                     }
                     LoginStart { name, .. } => {
-                        // Add player
-                        config.status.players.online += 1; // Probably shouldn't use this.
-                        let player: Player = Player::new(name);
-                        players.insert(conn_id, player.clone());
+                        connection.last_timeout = Instant::now();
 
-                        connection.state = ConnectionState::Play;
+                        // Players are authenticated by the proxy when forwarding is used
+                        match config.forwarding {
+                            Forwarding::BungeeCord => {
+                                let Some(forwarded) = connection.forwarded.take() else {
+                                    drop_connections.push(conn_id);
+                                    continue;
+                                };
+                                join(
+                                    conn_id,
+                                    forwarded.into_profile(name),
+                                    config,
+                                    state_sender,
+                                    packet_sender,
+                                    connection,
+                                    players,
+                                    worlds,
+                                    entity_ids,
+                                )?;
+                                continue;
+                            }
+                            Forwarding::Velocity => {
+                                send_packet(OutgoingPacket::LoginPluginRequest {
+                                    message_id: conn_id,
+                                    channel: VELOCITY_CHANNEL.to_string(),
+                                    data: vec![VELOCITY_FORWARDING_VERSION],
+                                })?;
 
-                        // Update state
+                                // Let the reader continue with Login Plugin Response
+                                state_sender.send(WrappedConnectionState {
+                                    conn_id,
+                                    state: connection.state,
+                                })?;
+                                continue;
+                            }
+                            Forwarding::None => {}
+                        }
+
+                        let Some(authenticator) = authenticator else {
+                            join(
+                                conn_id,
+                                GameProfile::offline(name),
+                                config,
+                                state_sender,
+                                packet_sender,
+                                connection,
+                                players,
+                                worlds,
+                                entity_ids,
+                            )?;
+                            continue;
+                        };
+
+                        connection.username = Some(name);
+                        connection.verify_token = Authenticator::gen_verify_token();
+                        send_packet(OutgoingPacket::EncryptionRequest {
+                            server_id: String::new(),
+                            public_key: authenticator.public_key.clone(),
+                            verify_token: connection.verify_token.to_vec(),
+                        })?;
+
+                        // Let the reader continue with Encryption Response
                         state_sender.send(WrappedConnectionState {
                             conn_id,
                             state: connection.state,
                         })?;
+                    }
+                    EncryptionResponse {
+                        shared_secret,
+                        verify_token,
+                    } => {
+                        let (Some(authenticator), Some(username)) =
+                            (authenticator, connection.username.take())
+                        else {
+                            drop_connections.push(conn_id);
+                            continue;
+                        };
 
-                        use OutgoingPacket::*;
-                        use PlayerInfoUpdateActions::*;
-                        send_packet(LoginSuccess {
-                            uuid: player.uuid,
-                            username: player.name.clone(),
-                        })?;
-                        send_packet(LoginPlay {
-                            entity_id: conn_id as i32,
-                            is_hardcore: IS_HARDCORE,
-                            gamemode: Gamemode::Creative,
-                            previous_gamemode: None,
-                            dimension_names: vec!["minecraft:overworld".to_string()],
-                            registry_codec: REGISTRY_CODEC.clone(),
-                            dimension_type: "minecraft:overworld".to_string(),
-                            dimension_name: "minecraft:overworld".to_string(),
-                            hashed_seed: *HASHED_SEED,
-                            max_players: config.status.players.max,
-                            view_distance: VIEW_DISTANCE,
-                            simulation_distance: SIMULATION_DISTANCE,
-                            reduced_debug_info: REDUCED_DEBUG_INFO,
-                            enable_respawn_screen: ENABLE_RESPAWN_SCREEN,
-                            is_debug: IS_DEBUG,
-                            is_flat: IS_FLAT,
-                            death_location: None,
-                            portal_cooldown: 0,
-                        })?;
-                        send_packet(SetCenterChunk {
-                            chunk_x: 0,
-                            chunk_z: 0,
-                        })?;
-                        send_packet(SetDefaultSpawnPosition {
-                            location: Position {
-                                x: DEFAULT_SPAWN_POSITION.0 as i32,
-                                y: DEFAULT_SPAWN_POSITION.1 as i16,
-                                z: DEFAULT_SPAWN_POSITION.2 as i32,
-                            },
-                            angle: 0.0,
-                        })?;
-                        for x in -VIEW_DISTANCE..=VIEW_DISTANCE {
-                            for z in -VIEW_DISTANCE..=VIEW_DISTANCE {
-                                send_packet(ChunkDataAndUpdateLight {
-                                    chunk_x: x,
-                                    chunk_z: z,
-                                    heightmaps: nbt!({}),
-                                    data: get_chunk(chunks, x, z),
-                                    block_entities: vec![],
-                                    sky_light_mask: vec![],
-                                    block_light_mask: vec![],
-                                    empty_sky_light_mask: vec![],
-                                    empty_block_light_mask: vec![],
-                                    sky_light_arrays: vec![],
-                                    block_light_arrays: vec![],
-                                })?;
+                        let shared_secret: [u8; 16] = match authenticator.decrypt_shared_secret(
+                            &shared_secret,
+                            &verify_token,
+                            &connection.verify_token,
+                        ) {
+                            Ok(shared_secret) => shared_secret,
+                            Err(e) => {
+                                info!("{username} failed to log in: {e}");
+                                drop_connections.push(conn_id);
+                                continue;
                             }
+                        };
+
+                        connection.last_timeout = Instant::now();
+                        send_packet(OutgoingPacket::EnableEncryption { shared_secret })?;
+
+                        // The session server is queried off the tick loop
+                        let profile_sender: UnboundedSender<WrappedGameProfile> =
+                            profile_sender.clone();
+                        let verify = authenticator.verify(username, shared_secret);
+                        tokio::spawn(async move {
+                            let profile: Result<GameProfile> = verify.await;
+                            profile_sender.send(WrappedGameProfile { conn_id, profile })
+                        });
+                    }
+                    LoginPluginResponse { message_id, data } => {
+                        if config.forwarding != Forwarding::Velocity || message_id != conn_id {
+                            drop_connections.push(conn_id);
+                            continue;
                         }
-                        send_packet(SynchronizePlayerPosition {
-                            x: player.x,
-                            y: player.y,
-                            z: player.z,
-                            yaw: player.yaw,
-                            pitch: player.pitch,
-                            flags: 0,
-                            teleport_id: 0,
-                        })?;
-                        send_packet(PlayerInfoUpdate {
-                            actions: 0x01 | 0x08,
-                            players: players
-                                .values()
-                                .map(|p| {
-                                    (
-                                        p.uuid,
-                                        vec![
-                                            AddPlayer {
-                                                name: p.name.clone(),
-                                                properties: vec![],
-                                            },
-                                            UpdateListed { listed: true },
-                                        ],
-                                    )
-                                })
-                                .collect(),
-                        })?;
-                        for (&id, p) in &*players {
-                            if id != conn_id {
-                                // Send information about other players
-                                send_packet(SpawnPlayer {
-                                    entity_id: id,
-                                    player_uuid: p.uuid,
-                                    x: p.x,
-                                    y: p.y,
-                                    z: p.z,
-                                    yaw: p.yaw,
-                                    pitch: p.pitch,
-                                })?;
-                                send_packet(SetHeadRotation {
-                                    entity_id: id,
-                                    head_yaw: p.yaw,
-                                })?;
-                                // Send information about self to other players
-                                send_packet_to(
-                                    id,
-                                    PlayerInfoUpdate {
-                                        actions: 0x01 | 0x08,
-                                        players: vec![(
-                                            player.uuid.clone(),
-                                            vec![
-                                                AddPlayer {
-                                                    name: player.name.clone(),
-                                                    properties: vec![],
-                                                },
-                                                UpdateListed { listed: true },
-                                            ],
-                                        )],
-                                    },
-                                )?;
-                                send_packet_to(
-                                    id,
-                                    SpawnPlayer {
-                                        entity_id: conn_id,
-                                        player_uuid: player.uuid,
-                                        x: player.x,
-                                        y: player.y,
-                                        z: player.z,
-                                        yaw: player.yaw,
-                                        pitch: player.pitch,
-                                    },
-                                )?;
-                                send_packet_to(
-                                    id,
-                                    SetHeadRotation {
-                                        entity_id: conn_id,
-                                        head_yaw: player.yaw,
-                                    },
+
+                        let forwarded = data
+                            .ok_or_else(|| eyre!("Player info was not forwarded"))
+                            .and_then(|data| {
+                                parse_velocity(&data, config.forwarding_secret.as_bytes())
+                            });
+                        match forwarded {
+                            Ok((forwarded, name)) => {
+                                connection.last_timeout = Instant::now();
+                                if let Err(rejection) =
+                                    connection.set_forwarded_ip(conn_id, forwarded.address)
+                                {
+                                    info!(
+                                        "Rejected forwarded connection from {}: {rejection}",
+                                        forwarded.address
+                                    );
+                                    drop_connections.push(conn_id);
+                                    continue;
+                                }
+                                join(
+                                    conn_id,
+                                    forwarded.into_profile(name),
+                                    config,
+                                    state_sender,
+                                    packet_sender,
+                                    connection,
+                                    players,
+                                    worlds,
+                                    entity_ids,
                                 )?;
                             }
+                            Err(e) => {
+                                info!(
+                                    "Failed to verify forwarded connection from {}: {e}",
+                                    connection.address
+                                );
+                                send_packet(OutgoingPacket::DisconnectLogin {
+                                    reason: "{\"text\":\"Unable to verify player details!\"}"
+                                        .to_string(),
+                                })?;
+                                drop_connections.push(conn_id);
+                            }
                         }
-                        send_packet(EntityEffect {
-                            entity_id: conn_id,
-                            effect_id: 16,
-                            amplifier: 0,
-                            duration: -1,
-                            flags: 0x02 | 0x04,
-                            factor_codec: None,
-                        })?;
-                        send_packet(SetTabListHeaderAndFooter {
-                            header: "{\"text\":\"\nUSSR 1.20.1\n\"}".to_string(),
-                            footer: "{\"text\":\"\n\"}".to_string(),
-                        })?;
-                        send_packet(SetContainerSlot {
-                            window_id: 0,
-                            state_id: 0,
-                            slot: 40,
-                            slot_data: Some((
-                                807,
-                                1,
-                                nbt!({"display": {"Name": "{\"text\":\"Magic Wand\",\"italic\":0,\"bold\":1,\"color\":\"gold\"}"}}),
-                            )),
-                        })?;
-                        connection.last_timeout = Instant::now();
                     }
                     KeepAlive { .. } => {
                         connection.last_timeout = Instant::now();
                     }
                     SetPlayerPosition { x, y, z, on_ground } => {
                         let player: &Player = players.get(&conn_id).unwrap();
-                        for &id in players.keys() {
-                            if id != conn_id {
+                        for (&id, p) in players.iter() {
+                            if id != conn_id && p.world == player.world {
                                 send_packet_to(
                                     id,
                                     OutgoingPacket::UpdateEntityPosition {
-                                        entity_id: conn_id,
+                                        entity_id: player.entity_id,
                                         dx: ((x * 32.0 - player.x * 32.0) * 128.0) as i16,
                                         dy: ((y * 32.0 - player.y * 32.0) * 128.0) as i16,
                                         dz: ((z * 32.0 - player.z * 32.0) * 128.0) as i16,
@@ -481,10 +836,15 @@ fn handle_packets(
                         }
 
                         let player: &mut Player = players.get_mut(&conn_id).unwrap();
+                        let chunk: (i32, i32) = player.get_chunk();
                         player.x = x;
                         player.y = y;
                         player.z = z;
                         player.on_ground = on_ground;
+                        if player.get_chunk() != chunk {
+                            let world: &mut World = worlds.get_mut(&player.world).unwrap();
+                            send_chunks(conn_id, player, world, packet_sender)?;
+                        }
                     }
                     SetPlayerPositionAndRotation {
                         x,
@@ -497,12 +857,12 @@ fn handle_packets(
                         yaw = normalize_yaw(yaw);
 
                         let player: &Player = players.get(&conn_id).unwrap();
-                        for &id in players.keys() {
-                            if id != conn_id {
+                        for (&id, p) in players.iter() {
+                            if id != conn_id && p.world == player.world {
                                 send_packet_to(
                                     id,
                                     OutgoingPacket::UpdateEntityPositionAndRotation {
-                                        entity_id: conn_id,
+                                        entity_id: player.entity_id,
                                         dx: ((x * 32.0 - player.x * 32.0) * 128.0) as i16,
                                         dy: ((y * 32.0 - player.y * 32.0) * 128.0) as i16,
                                         dz: ((z * 32.0 - player.z * 32.0) * 128.0) as i16,
@@ -514,7 +874,7 @@ fn handle_packets(
                                 send_packet_to(
                                     id,
                                     OutgoingPacket::SetHeadRotation {
-                                        entity_id: conn_id,
+                                        entity_id: player.entity_id,
                                         head_yaw: yaw,
                                     },
                                 )?;
@@ -522,12 +882,17 @@ fn handle_packets(
                         }
 
                         let player: &mut Player = players.get_mut(&conn_id).unwrap();
+                        let chunk: (i32, i32) = player.get_chunk();
                         player.x = x;
                         player.y = y;
                         player.z = z;
                         player.yaw = yaw;
                         player.pitch = pitch;
                         player.on_ground = on_ground;
+                        if player.get_chunk() != chunk {
+                            let world: &mut World = worlds.get_mut(&player.world).unwrap();
+                            send_chunks(conn_id, player, world, packet_sender)?;
+                        }
                     }
                     SetPlayerRotation {
                         mut yaw,
@@ -537,12 +902,12 @@ fn handle_packets(
                         yaw = normalize_yaw(yaw);
 
                         let player: &Player = players.get(&conn_id).unwrap();
-                        for &id in players.keys() {
-                            if id != conn_id {
+                        for (&id, p) in players.iter() {
+                            if id != conn_id && p.world == player.world {
                                 send_packet_to(
                                     id,
                                     OutgoingPacket::UpdateEntityRotation {
-                                        entity_id: conn_id,
+                                        entity_id: player.entity_id,
                                         yaw,
                                         pitch,
                                         on_ground: player.on_ground,
@@ -551,7 +916,7 @@ fn handle_packets(
                                 send_packet_to(
                                     id,
                                     OutgoingPacket::SetHeadRotation {
-                                        entity_id: conn_id,
+                                        entity_id: player.entity_id,
                                         head_yaw: yaw,
                                     },
                                 )?;
@@ -563,6 +928,153 @@ fn handle_packets(
                         player.pitch = pitch;
                         player.on_ground = on_ground;
                     }
+                    PlayerAction {
+                        status,
+                        location,
+                        face: _,
+                        sequence,
+                    } => {
+                        let player: &mut Player = players.get_mut(&conn_id).unwrap();
+                        let world: &mut World = worlds.get_mut(&player.world).unwrap();
+                        let breaks: bool = match (status, player.gamemode) {
+                            (DiggingStatus::Started | DiggingStatus::Finished, _)
+                                if !player.can_reach(location) =>
+                            {
+                                false
+                            }
+                            (DiggingStatus::Started, Gamemode::Creative) => true,
+                            (DiggingStatus::Started, Gamemode::Survival) => {
+                                let hardness: Option<f32> = world
+                                    .get_block(location.x, location.y as i32, location.z)
+                                    .and_then(|b| b.hardness());
+                                let speed: f32 = player
+                                    .inventory
+                                    .get(&(HOTBAR_SLOT + player.selected_slot))
+                                    .and_then(|&item_id| ItemInfo::from_id(item_id))
+                                    .map_or(1.0, ItemInfo::mining_speed);
+                                match hardness.map(|hardness| break_time(hardness, speed)) {
+                                    Some(time) if time.is_zero() => true,
+                                    // Wait for the client to finish breaking it
+                                    Some(time) => {
+                                        let earliest: Instant =
+                                            Instant::now() + time.mul_f32(BREAK_TIME_TOLERANCE);
+                                        player.digging = Some((location, earliest));
+                                        false
+                                    }
+                                    None => false,
+                                }
+                            }
+                            (DiggingStatus::Cancelled, _) => {
+                                player.digging = None;
+                                false
+                            }
+                            (DiggingStatus::Finished, Gamemode::Survival) => {
+                                matches!(player.digging.take(), Some((digging, earliest))
+                                    if digging == location && Instant::now() >= earliest)
+                            }
+                            (DiggingStatus::Started | DiggingStatus::Finished, _) => false,
+                            // Dropping and swapping items do not change blocks
+                            _ => continue,
+                        };
+
+                        if breaks {
+                            update_block(location, Block::Air, world, players, packet_sender)?;
+                        } else if let Some(block) =
+                            world.get_block(location.x, location.y as i32, location.z)
+                        {
+                            // The client may already show the block as broken
+                            send_packet(OutgoingPacket::BlockUpdate {
+                                location,
+                                block_id: block.get_state_id() as i32,
+                            })?;
+                        }
+                        send_packet(OutgoingPacket::AcknowledgeBlockChange {
+                            sequence_id: sequence,
+                        })?;
+                    }
+                    SetHeldItem { slot } if (0..9).contains(&slot) => {
+                        players.get_mut(&conn_id).unwrap().selected_slot = slot;
+                    }
+                    SetCreativeModeSlot { slot, clicked_item } => {
+                        let player: &mut Player = players.get_mut(&conn_id).unwrap();
+                        if player.gamemode != Gamemode::Creative {
+                            continue;
+                        }
+                        match clicked_item {
+                            Some((item_id, _, _)) => player.inventory.insert(slot, item_id),
+                            None => player.inventory.remove(&slot),
+                        };
+                    }
+                    UseItemOn {
+                        hand,
+                        location,
+                        face,
+                        sequence,
+                        ..
+                    } => {
+                        let player: &Player = players.get(&conn_id).unwrap();
+                        let world: &mut World = worlds.get_mut(&player.world).unwrap();
+                        let slot: i16 = match hand {
+                            0 => HOTBAR_SLOT + player.selected_slot,
+                            _ => OFFHAND_SLOT,
+                        };
+                        let block: Option<Block> = match player.gamemode {
+                            Gamemode::Survival | Gamemode::Creative => player
+                                .inventory
+                                .get(&slot)
+                                .and_then(|&item_id| ItemInfo::from_id(item_id))
+                                .and_then(ItemInfo::block),
+                            // Adventure and spectator players cannot place blocks
+                            _ => None,
+                        };
+
+                        if let Some(block) = block {
+                            let is_replaceable = |world: &World, location: Position| {
+                                world
+                                    .get_block(location.x, location.y as i32, location.z)
+                                    .is_some_and(|b| b.is_replaceable())
+                            };
+                            // Blocks like grass are replaced instead of being placed against
+                            let location: Position = if is_replaceable(world, location) {
+                                location
+                            } else {
+                                let (dx, dy, dz): (i32, i32, i32) = face.offset();
+                                Position {
+                                    x: location.x + dx,
+                                    y: location.y + dy as i16,
+                                    z: location.z + dz,
+                                }
+                            };
+                            if player.can_reach(location)
+                                && is_replaceable(world, location)
+                                && !(block.blocks_motion()
+                                    && players
+                                        .values()
+                                        .any(|p| p.world == player.world && p.intersects(location)))
+                            {
+                                update_block(location, block, world, players, packet_sender)?;
+                            } else if let Some(block) =
+                                world.get_block(location.x, location.y as i32, location.z)
+                            {
+                                // The client may already show the block as placed
+                                send_packet(OutgoingPacket::BlockUpdate {
+                                    location,
+                                    block_id: block.get_state_id() as i32,
+                                })?;
+                            }
+                        }
+                        send_packet(OutgoingPacket::AcknowledgeBlockChange {
+                            sequence_id: sequence,
+                        })?;
+                    }
+                    ChatCommand { command } => {
+                        let message: String =
+                            run_command(conn_id, &command, config, players, worlds, packet_sender)?;
+                        send_packet(OutgoingPacket::SystemChatMessage {
+                            content: json!({ "text": message }).to_string(),
+                            overlay: false,
+                        })?;
+                    }
                     UseItem { .. } => {
                         let p: &mut Player = players.get_mut(&conn_id).unwrap();
 
@@ -613,3 +1125,476 @@ fn handle_packets(
 
     Ok(())
 }
+
+/// Runs a command sent by a player, and returns the message to answer with.
+fn run_command(
+    conn_id: i32,
+    command: &str,
+    config: &Config,
+    players: &mut HashMap<i32, Player>,
+    worlds: &mut HashMap<String, World>,
+    packet_sender: &UnboundedSender<WrappedOutgoingPacket>,
+) -> Result<String> {
+    let player: &Player = players.get(&conn_id).unwrap();
+    let args: Vec<&str> = command.split_whitespace().collect();
+    Ok(match args[..] {
+        ["world"] => {
+            let mut names: Vec<&str> = worlds.keys().map(String::as_str).collect();
+            names.sort();
+            format!("You are in {}. Worlds: {}", player.world, names.join(", "))
+        }
+        ["world", name] => {
+            // Like vanilla, names without a namespace are in minecraft
+            let name: String = if name.contains(':') {
+                name.to_string()
+            } else {
+                format!("minecraft:{name}")
+            };
+            if name == player.world {
+                format!("You are already in {name}")
+            } else if worlds.contains_key(&name) {
+                change_world(conn_id, &name, players, worlds, packet_sender)?;
+                format!("Moved to {name}")
+            } else {
+                format!("There is no world called {name}")
+            }
+        }
+        ["paste", ..] if !config.operators.contains(&player.name) => {
+            "Only operators can paste schematics".to_string()
+        }
+        ["paste", file, ref origin @ ..] => {
+            let origin: (i32, i32, i32) = match origin {
+                [] => player.get_block(),
+                [x, y, z] => match (x.parse(), y.parse(), z.parse()) {
+                    (Ok(x), Ok(y), Ok(z)) => (x, y, z),
+                    _ => return Ok(format!("Invalid position {x} {y} {z}")),
+                },
+                _ => return Ok("Usage: /paste <file> [<x> <y> <z>]".to_string()),
+            };
+            // Only files in the schematics directory can be pasted
+            if !Path::new(file)
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+            {
+                return Ok(format!("Invalid file name {file}"));
+            }
+            let schematic: Schematic =
+                match Schematic::load(&Path::new(&config.schematics_directory).join(file)) {
+                    Ok(schematic) => schematic,
+                    Err(e) => return Ok(format!("{e:#}")),
+                };
+
+            // Players are sent the chunks once workers changed them
+            let world: &mut World = worlds.get_mut(&player.world).unwrap();
+            let changed: Vec<(i32, i32)> = world.paste(&schematic, origin);
+            info!(
+                "{} pasted {file} into {} at {} {} {}",
+                player.name, player.world, origin.0, origin.1, origin.2
+            );
+            let (width, height, length): (i32, i32, i32) = schematic.size;
+            format!(
+                "Pasting {file} ({width}x{height}x{length}), changing {} chunks",
+                changed.len()
+            )
+        }
+        _ => format!("Unknown command: /{command}"),
+    })
+}
+
+/// Finishes logging in and spawns the player.
+#[allow(clippy::too_many_arguments)]
+fn join(
+    conn_id: i32,
+    profile: GameProfile,
+    config: &mut Config,
+    state_sender: &UnboundedSender<WrappedConnectionState>,
+    packet_sender: &UnboundedSender<WrappedOutgoingPacket>,
+    connection: &mut Connection,
+    players: &mut HashMap<i32, Player>,
+    worlds: &mut HashMap<String, World>,
+    entity_ids: &mut EntityIds,
+) -> Result<()> {
+    let send_packet_to = |conn_id: i32, p: OutgoingPacket| {
+        packet_sender.send(WrappedOutgoingPacket { conn_id, packet: p })
+    };
+    let send_packet = |p: OutgoingPacket| send_packet_to(conn_id, p);
+
+    // Players join the first world
+    let dimension_names: Vec<String> = worlds.keys().cloned().collect();
+    let world: &mut World = worlds.get_mut(&config.worlds[0].name).unwrap();
+    let player: Player = Player::new(
+        entity_ids.allocate(),
+        profile,
+        config.gamemode,
+        world.name().to_string(),
+    );
+    info!("{} joined from {}", player.name, connection.address);
+
+    // Add player
+    config.status.players.online += 1; // Probably shouldn't use this.
+    players.insert(conn_id, player.clone());
+
+    connection.state = ConnectionState::Play;
+
+    // Update state
+    state_sender.send(WrappedConnectionState {
+        conn_id,
+        state: connection.state,
+    })?;
+
+    use OutgoingPacket::*;
+    use PlayerInfoUpdateActions::*;
+    if config.compression_threshold >= 0 {
+        send_packet(SetCompression {
+            threshold: config.compression_threshold,
+        })?;
+    }
+    send_packet(LoginSuccess {
+        uuid: player.uuid,
+        username: player.name.clone(),
+        properties: player.properties.clone(),
+    })?;
+    send_packet(LoginPlay(Box::new(LoginPlayData {
+        entity_id: player.entity_id,
+        is_hardcore: IS_HARDCORE,
+        gamemode: player.gamemode,
+        previous_gamemode: None,
+        dimension_names,
+        registry_codec: REGISTRY_CODEC.clone(),
+        dimension_type: world.dimension().type_name().to_string(),
+        dimension_name: world.name().to_string(),
+        hashed_seed: *HASHED_SEED,
+        max_players: config.status.players.max,
+        view_distance: VIEW_DISTANCE,
+        simulation_distance: SIMULATION_DISTANCE,
+        reduced_debug_info: REDUCED_DEBUG_INFO,
+        enable_respawn_screen: ENABLE_RESPAWN_SCREEN,
+        is_debug: IS_DEBUG,
+        is_flat: world.is_flat(),
+        death_location: None,
+        portal_cooldown: 0,
+    })))?;
+    send_packet(SetDefaultSpawnPosition {
+        location: Position {
+            x: DEFAULT_SPAWN_POSITION.0 as i32,
+            y: DEFAULT_SPAWN_POSITION.1 as i16,
+            z: DEFAULT_SPAWN_POSITION.2 as i32,
+        },
+        angle: 0.0,
+    })?;
+    send_chunks(
+        conn_id,
+        players.get_mut(&conn_id).unwrap(),
+        world,
+        packet_sender,
+    )?;
+    send_packet(SynchronizePlayerPosition {
+        x: player.x,
+        y: player.y,
+        z: player.z,
+        yaw: player.yaw,
+        pitch: player.pitch,
+        flags: 0,
+        teleport_id: 0,
+    })?;
+    send_packet(PlayerInfoUpdate {
+        actions: 0x01 | 0x08,
+        players: players
+            .values()
+            .map(|p| {
+                (
+                    p.uuid,
+                    vec![
+                        AddPlayer {
+                            name: p.name.clone(),
+                            properties: p.properties.clone(),
+                        },
+                        UpdateListed { listed: true },
+                    ],
+                )
+            })
+            .collect(),
+    })?;
+    for &id in players.keys() {
+        if id != conn_id {
+            // Send information about self to other players
+            send_packet_to(
+                id,
+                PlayerInfoUpdate {
+                    actions: 0x01 | 0x08,
+                    players: vec![(
+                        player.uuid,
+                        vec![
+                            AddPlayer {
+                                name: player.name.clone(),
+                                properties: player.properties.clone(),
+                            },
+                            UpdateListed { listed: true },
+                        ],
+                    )],
+                },
+            )?;
+        }
+    }
+    spawn_players(conn_id, players, packet_sender)?;
+    send_packet(EntityEffect {
+        entity_id: player.entity_id,
+        effect_id: 16,
+        amplifier: 0,
+        duration: -1,
+        flags: 0x02 | 0x04,
+        factor_codec: None,
+    })?;
+    send_packet(SetTabListHeaderAndFooter {
+        header: "{\"text\":\"\nUSSR 1.20.1\n\"}".to_string(),
+        footer: "{\"text\":\"\n\"}".to_string(),
+    })?;
+    send_packet(SetContainerSlot {
+        window_id: 0,
+        state_id: 0,
+        slot: 40,
+        slot_data: Some((
+            807,
+            1,
+            nbt!({"display": {"Name": "{\"text\":\"Magic Wand\",\"italic\":0,\"bold\":1,\"color\":\"gold\"}"}}),
+        )),
+    })?;
+    players.get_mut(&conn_id).unwrap().inventory.insert(40, 807);
+    connection.last_timeout = Instant::now();
+
+    Ok(())
+}
+
+/// Spawns a player for the other players in its world, and them for the player.
+fn spawn_players(
+    conn_id: i32,
+    players: &HashMap<i32, Player>,
+    packet_sender: &UnboundedSender<WrappedOutgoingPacket>,
+) -> Result<()> {
+    let send_packet_to = |conn_id: i32, p: OutgoingPacket| {
+        packet_sender.send(WrappedOutgoingPacket { conn_id, packet: p })
+    };
+
+    let player: &Player = &players[&conn_id];
+    for (&id, p) in players {
+        if id == conn_id || p.world != player.world {
+            continue;
+        }
+        for (to, from) in [(conn_id, p), (id, player)] {
+            send_packet_to(
+                to,
+                OutgoingPacket::SpawnPlayer {
+                    entity_id: from.entity_id,
+                    player_uuid: from.uuid,
+                    x: from.x,
+                    y: from.y,
+                    z: from.z,
+                    yaw: from.yaw,
+                    pitch: from.pitch,
+                },
+            )?;
+            send_packet_to(
+                to,
+                OutgoingPacket::SetHeadRotation {
+                    entity_id: from.entity_id,
+                    head_yaw: from.yaw,
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves a player to the spawn of another world, which the client loads like after going
+/// through a portal.
+fn change_world(
+    conn_id: i32,
+    name: &str,
+    players: &mut HashMap<i32, Player>,
+    worlds: &mut HashMap<String, World>,
+    packet_sender: &UnboundedSender<WrappedOutgoingPacket>,
+) -> Result<()> {
+    let send_packet_to = |conn_id: i32, p: OutgoingPacket| {
+        packet_sender.send(WrappedOutgoingPacket { conn_id, packet: p })
+    };
+    let send_packet = |p: OutgoingPacket| send_packet_to(conn_id, p);
+
+    // Despawn the player in its old world
+    let player: &Player = &players[&conn_id];
+    for (&id, p) in players.iter() {
+        if id != conn_id && p.world == player.world {
+            send_packet_to(
+                id,
+                OutgoingPacket::RemoveEntities {
+                    entity_ids: vec![player.entity_id],
+                },
+            )?;
+        }
+    }
+
+    // The client drops every chunk of the old world
+    let player: &mut Player = players.get_mut(&conn_id).unwrap();
+    let old: &mut World = worlds.get_mut(&player.world).unwrap();
+    for (x, z) in player.loaded_chunks.drain() {
+        old.remove_viewer(x, z);
+    }
+    player.world = name.to_string();
+    (player.x, player.y, player.z) = DEFAULT_SPAWN_POSITION;
+    player.digging = None;
+
+    let world: &mut World = worlds.get_mut(name).unwrap();
+    send_packet(OutgoingPacket::Respawn(Box::new(RespawnData {
+        dimension_type: world.dimension().type_name().to_string(),
+        dimension_name: world.name().to_string(),
+        hashed_seed: *HASHED_SEED,
+        gamemode: player.gamemode,
+        previous_gamemode: None,
+        is_debug: IS_DEBUG,
+        is_flat: world.is_flat(),
+        data_kept: 0x01,
+        death_location: None,
+        portal_cooldown: 0,
+    })))?;
+    send_packet(OutgoingPacket::SetDefaultSpawnPosition {
+        location: Position {
+            x: DEFAULT_SPAWN_POSITION.0 as i32,
+            y: DEFAULT_SPAWN_POSITION.1 as i16,
+            z: DEFAULT_SPAWN_POSITION.2 as i32,
+        },
+        angle: 0.0,
+    })?;
+    send_chunks(conn_id, player, world, packet_sender)?;
+    send_packet(OutgoingPacket::SynchronizePlayerPosition {
+        x: player.x,
+        y: player.y,
+        z: player.z,
+        yaw: player.yaw,
+        pitch: player.pitch,
+        flags: 0,
+        teleport_id: 0,
+    })?;
+
+    // The client forgets the effects and inventory of the player
+    send_packet(OutgoingPacket::EntityEffect {
+        entity_id: player.entity_id,
+        effect_id: 16,
+        amplifier: 0,
+        duration: -1,
+        flags: 0x02 | 0x04,
+        factor_codec: None,
+    })?;
+    send_packet(OutgoingPacket::SetContainerContent {
+        window_id: 0,
+        state_id: 0,
+        slot_data: (0..=OFFHAND_SLOT)
+            .map(|slot| player.inventory.get(&slot).map(|&id| (id, 1, nbt!({}))))
+            .collect(),
+        carried_item: None,
+    })?;
+
+    spawn_players(conn_id, players, packet_sender)
+}
+
+/// Centers the view of the player on its chunk, unloads the chunks that are out of view
+/// and sends those that are ready.
+fn send_chunks(
+    conn_id: i32,
+    player: &mut Player,
+    world: &mut World,
+    packet_sender: &UnboundedSender<WrappedOutgoingPacket>,
+) -> Result<()> {
+    let send_packet =
+        |packet: OutgoingPacket| packet_sender.send(WrappedOutgoingPacket { conn_id, packet });
+
+    let (center_x, center_z) = player.get_chunk();
+    send_packet(OutgoingPacket::SetCenterChunk {
+        chunk_x: center_x,
+        chunk_z: center_z,
+    })?;
+
+    let out_of_view: Vec<(i32, i32)> = player
+        .loaded_chunks
+        .iter()
+        .copied()
+        .filter(|&(x, z)| (x - center_x).abs().max((z - center_z).abs()) > VIEW_DISTANCE)
+        .collect();
+    for (x, z) in out_of_view {
+        player.loaded_chunks.remove(&(x, z));
+        world.remove_viewer(x, z);
+        send_packet(OutgoingPacket::UnloadChunk {
+            chunk_x: x,
+            chunk_z: z,
+        })?;
+    }
+
+    send_ready_chunks(conn_id, player, world, packet_sender)
+}
+
+/// Sends the chunks in view that the player does not have yet and that are ready,
+/// closest first, and asks the world for the others.
+fn send_ready_chunks(
+    conn_id: i32,
+    player: &mut Player,
+    world: &mut World,
+    packet_sender: &UnboundedSender<WrappedOutgoingPacket>,
+) -> Result<()> {
+    let (center_x, center_z) = player.get_chunk();
+    for (x, z) in spiral_chunks(center_x, center_z, VIEW_DISTANCE) {
+        if player.loaded_chunks.contains(&(x, z)) {
+            continue;
+        }
+        if let Some(data) = world.get_chunk_data(x, z) {
+            player.loaded_chunks.insert((x, z));
+            world.add_viewer(x, z);
+            packet_sender.send(WrappedOutgoingPacket {
+                conn_id,
+                packet: OutgoingPacket::ChunkDataAndUpdateLight {
+                    chunk_x: x,
+                    chunk_z: z,
+                    data,
+                },
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// How long it takes to break a block with a tool of a mining speed, as in vanilla,
+/// but as if the tool could harvest every block.
+pub fn break_time(hardness: f32, speed: f32) -> Duration {
+    let progress: f32 = speed / hardness / 30.0;
+    if progress >= 1.0 {
+        return Duration::ZERO;
+    }
+    Duration::from_millis(50) * (1.0 / progress).ceil() as u32
+}
+
+/// Replaces a block and sends it to the players that have its chunk loaded.
+/// Returns `false` if the block is out of bounds.
+fn update_block(
+    location: Position,
+    block: Block,
+    world: &mut World,
+    players: &HashMap<i32, Player>,
+    packet_sender: &UnboundedSender<WrappedOutgoingPacket>,
+) -> Result<bool> {
+    if !world.set_block(location.x, location.y as i32, location.z, block) {
+        return Ok(false);
+    }
+
+    let chunk: (i32, i32) = (location.x.div_euclid(16), location.z.div_euclid(16));
+    for (&conn_id, player) in players {
+        if player.world == world.name() && player.loaded_chunks.contains(&chunk) {
+            packet_sender.send(WrappedOutgoingPacket {
+                conn_id,
+                packet: OutgoingPacket::BlockUpdate {
+                    location,
+                    block_id: block.get_state_id() as i32,
+                },
+            })?;
+        }
+    }
+    Ok(true)
+}
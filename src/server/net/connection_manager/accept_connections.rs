@@ -1,61 +1,199 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    sync::{atomic::AtomicI32, Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::Local;
 use eyre::{Context, Result};
+use log::{debug, warn};
 use tokio::{
-    net::TcpListener,
+    fs::create_dir_all,
+    net::{TcpListener, TcpStream},
+    select,
     sync::{
-        mpsc::{unbounded_channel, UnboundedSender},
+        mpsc::{channel, unbounded_channel, UnboundedSender},
         watch,
     },
+    time::{sleep, timeout},
 };
 
-use crate::server::state::ConnectionState;
+use crate::{
+    config::{ConnectionLimits, SendQueueLimits},
+    server::{
+        net::{
+            encryption::{EncryptedReader, EncryptedWriter},
+            recording::Recorder,
+        },
+        state::ConnectionState,
+    },
+};
 
 use super::{
+    proxy_protocol::read_proxy_header,
     receive_packets::receive_packets,
     send_packets::send_packets,
-    types::{ReceiveHalf, SendHalf, WrappedIncomingPacket},
+    throttle::Throttle,
+    types::{ReceiveHalf, SendHalf, SendQueue, WrappedConnection, WrappedIncomingPacket},
 };
 
+/// How long a proxy has to send the PROXY protocol header.
+const PROXY_HEADER_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[allow(clippy::too_many_arguments)]
 pub async fn accept_connections(
     listener: TcpListener,
+    limits: ConnectionLimits,
+    proxy_protocol: bool,
+    forwarded: bool,
+    send_queue_limits: SendQueueLimits,
+    recordings_directory: Option<PathBuf>,
+    connection_sender: UnboundedSender<WrappedConnection>,
     incoming_packet_sender: UnboundedSender<WrappedIncomingPacket>,
     receive_half_sender: UnboundedSender<ReceiveHalf>,
     send_half_sender: UnboundedSender<SendHalf>,
 ) -> Result<()> {
-    let mut conn_id: i32 = 0; // Funny thing, this actually determines entity IDs
+    let mut conn_id: i32 = 0;
+    if let Some(directory) = &recordings_directory {
+        create_dir_all(directory)
+            .await
+            .wrap_err_with(|| format!("Could not create {}", directory.display()))?;
+    }
+    // The server moves connections to the addresses forwarded by proxies
+    let throttle: Arc<Mutex<Throttle>> = Arc::new(Mutex::new(Throttle::new(limits, forwarded)));
+    // Receive tasks report the connection and whether the handshake failed when they end
+    let (closed_sender, mut closed_receiver) = unbounded_channel::<(i32, IpAddr, bool)>();
+    // Connections are only set up once the address of the client is known
+    let (accepted_sender, mut accepted_receiver) = unbounded_channel::<(TcpStream, SocketAddr)>();
 
     loop {
-        // TODO: A HashSet is needed to block connections from the same IP
-        if let Ok((stream, _)) = listener.accept().await {
-            let (read_half, write_half) = stream.into_split();
-
-            let (state_sender, state_receiver) = watch::channel(ConnectionState::Handshake);
-            // let (incoming_packet_sender, incoming_packet_receiver) = unbounded_channel();
-            let (outgoing_packet_sender, outgoing_packet_receiver) = unbounded_channel();
-            let send_half: SendHalf = SendHalf {
-                conn_id,
-                outgoing_packet_sender,
-            };
-            let receive_half: ReceiveHalf = ReceiveHalf {
-                conn_id,
-                state_sender,
-            };
-
-            tokio::spawn(receive_packets(
-                conn_id,
-                read_half,
-                state_receiver,
-                incoming_packet_sender.clone(),
-            ));
-            tokio::spawn(send_packets(write_half, outgoing_packet_receiver));
-
-            receive_half_sender
-                .send(receive_half)
-                .context("Receive half channel closed")?;
-            send_half_sender
-                .send(send_half)
-                .context("Send half channel closed")?;
-
-            conn_id += 1;
+        select! {
+            Ok((stream, address)) = listener.accept() => {
+                if !proxy_protocol {
+                    let _ = accepted_sender.send((stream, address));
+                    continue;
+                }
+
+                // Reading the header must not hold up other connections
+                let accepted_sender: UnboundedSender<(TcpStream, SocketAddr)> =
+                    accepted_sender.clone();
+                tokio::spawn(async move {
+                    let mut stream: TcpStream = stream;
+                    match timeout(PROXY_HEADER_TIMEOUT, read_proxy_header(&mut stream)).await {
+                        Ok(Ok(client_address)) => {
+                            let _ = accepted_sender.send((stream, client_address.unwrap_or(address)));
+                        }
+                        Ok(Err(e)) => debug!("Invalid PROXY protocol header from {address}: {e}"),
+                        Err(_) => debug!("No PROXY protocol header from {address}"),
+                    }
+                });
+            }
+            Some((stream, address)) = accepted_receiver.recv() => {
+                let ip: IpAddr = address.ip();
+                if let Err(rejection) = throttle.lock().unwrap().try_accept(conn_id, ip) {
+                    // Dropping the stream closes the socket
+                    debug!("Rejected connection from {address}: {rejection}");
+                    continue;
+                }
+                debug!("Accepted connection {conn_id} from {address}");
+
+                let (read_half, write_half) = stream.into_split();
+
+                let recorder: Option<Recorder> = match &recordings_directory {
+                    Some(directory) => {
+                        let path: PathBuf = directory.join(format!(
+                            "{}-{conn_id}.mcrec",
+                            Local::now().format("%Y%m%d-%H%M%S")
+                        ));
+                        match Recorder::create(&path).await {
+                            Ok(recorder) => Some(recorder),
+                            Err(e) => {
+                                warn!("Could not record connection {conn_id}: {e}");
+                                None
+                            }
+                        }
+                    }
+                    None => None,
+                };
+
+                let (state_sender, state_receiver) = watch::channel(ConnectionState::Handshake);
+                // Shared by both halves, since compression is enabled for both directions at once
+                let compression_threshold: Arc<AtomicI32> = Arc::new(AtomicI32::new(-1));
+                // The writer passes the shared secret on to the reader when encryption is enabled
+                let (shared_secret_sender, shared_secret_receiver) = watch::channel(None);
+                // let (incoming_packet_sender, incoming_packet_receiver) = unbounded_channel();
+                let (outgoing_packet_sender, outgoing_packet_receiver) =
+                    channel(send_queue_limits.capacity);
+                let send_queue: Arc<SendQueue> = Arc::new(SendQueue::default());
+
+                // The server learns about the connection before any packet from it
+                connection_sender
+                    .send(WrappedConnection {
+                        conn_id,
+                        address,
+                        send_queue: send_queue.clone(),
+                        throttle: throttle.clone(),
+                    })
+                    .context("Connection channel closed")?;
+                let send_half: SendHalf = SendHalf {
+                    conn_id,
+                    outgoing_packet_sender,
+                    send_queue: send_queue.clone(),
+                };
+                let receive_half: ReceiveHalf = ReceiveHalf {
+                    conn_id,
+                    state_sender,
+                };
+
+                let mut disconnect_receiver = state_receiver.clone();
+                let receive_task = receive_packets(
+                    conn_id,
+                    EncryptedReader::new(read_half),
+                    state_receiver,
+                    compression_threshold.clone(),
+                    shared_secret_receiver,
+                    incoming_packet_sender.clone(),
+                    recorder.clone(),
+                );
+                let closed_sender: UnboundedSender<(i32, IpAddr, bool)> = closed_sender.clone();
+                tokio::spawn(async move {
+                    select! {
+                        _ = receive_task => {}
+                        // The server dropped the connection, the state sender is gone.
+                        // Give the client a moment to read whatever was sent last.
+                        _ = async {
+                            while disconnect_receiver.changed().await.is_ok() {}
+                            sleep(Duration::from_secs(1)).await;
+                        } => {}
+                    }
+
+                    // Connections that never got past the Handshake state failed it
+                    let handshake_failed: bool =
+                        *disconnect_receiver.borrow() == ConnectionState::Handshake;
+                    let _ = closed_sender.send((conn_id, ip, handshake_failed));
+                });
+                tokio::spawn(send_packets(
+                    EncryptedWriter::new(write_half),
+                    outgoing_packet_receiver,
+                    send_queue,
+                    compression_threshold,
+                    shared_secret_sender,
+                    recorder,
+                ));
+
+                receive_half_sender
+                    .send(receive_half)
+                    .context("Receive half channel closed")?;
+                send_half_sender
+                    .send(send_half)
+                    .context("Send half channel closed")?;
+
+                conn_id += 1;
+            }
+            Some((conn_id, ip, handshake_failed)) = closed_receiver.recv() => {
+                throttle.lock().unwrap().release(conn_id, ip, handshake_failed);
+            }
         }
     }
 }
@@ -1,3 +1,7 @@
+use std::path::PathBuf;
+
+use crate::config::{ConnectionLimits, SendQueueLimits};
+
 use tokio::{
     net::TcpListener,
     sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
@@ -7,23 +11,34 @@ use self::{
     accept_connections::accept_connections,
     receive_manager::receive_manager,
     send_manager::send_manager,
-    types::{WrappedConnectionState, WrappedIncomingPacket, WrappedOutgoingPacket},
+    types::{
+        WrappedConnection, WrappedConnectionState, WrappedIncomingPacket, WrappedOutgoingPacket,
+    },
 };
 
 mod accept_connections;
+pub mod proxy_protocol;
 mod receive_manager;
 mod receive_packets;
-mod send_manager;
+pub mod send_manager;
 mod send_packets;
+pub mod throttle;
 pub mod types;
 
 pub fn init(
     listener: TcpListener,
+    limits: ConnectionLimits,
+    proxy_protocol: bool,
+    forwarded: bool,
+    send_queue_limits: SendQueueLimits,
+    recordings_directory: Option<PathBuf>,
 ) -> (
+    UnboundedReceiver<WrappedConnection>,
     UnboundedSender<WrappedConnectionState>,
     UnboundedReceiver<WrappedIncomingPacket>,
     UnboundedSender<WrappedOutgoingPacket>,
 ) {
+    let (connection_sender, connection_receiver) = unbounded_channel();
     let (state_sender, state_receiver) = unbounded_channel();
     let (incoming_packet_sender, incoming_packet_receiver) = unbounded_channel();
     let (outgoing_packet_sender, outgoing_packet_receiver) = unbounded_channel();
@@ -32,14 +47,25 @@ pub fn init(
 
     tokio::spawn(accept_connections(
         listener,
+        limits,
+        proxy_protocol,
+        forwarded,
+        send_queue_limits,
+        recordings_directory,
+        connection_sender,
         incoming_packet_sender,
         receive_half_sender,
         send_half_sender,
     ));
     tokio::spawn(receive_manager(state_receiver, receive_half_receiver));
-    tokio::spawn(send_manager(outgoing_packet_receiver, send_half_receiver));
+    tokio::spawn(send_manager(
+        send_queue_limits,
+        outgoing_packet_receiver,
+        send_half_receiver,
+    ));
 
     (
+        connection_receiver,
         state_sender,
         incoming_packet_receiver,
         outgoing_packet_sender,
@@ -0,0 +1,90 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use eyre::{bail, ensure, Context, Result};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+const V1_PREFIX: &[u8] = b"PROXY ";
+/// Including the trailing CRLF.
+const V1_MAX_LENGTH: usize = 107;
+const V2_SIGNATURE: &[u8; 12] = b"\r\n\r\n\0\r\nQUIT\n";
+
+/// Reads a PROXY protocol v1 or v2 header, as sent by HAProxy and similar load balancers.
+///
+/// Returns the address of the client, or `None` if the header does not carry one
+/// (e.g. health checks by the proxy itself).
+/// Reads exactly the header, so the Minecraft handshake can be read right after it.
+pub async fn read_proxy_header<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<SocketAddr>> {
+    match reader.read_u8().await? {
+        b'P' => read_v1(reader).await,
+        b'\r' => read_v2(reader).await,
+        _ => bail!("Missing PROXY protocol header"),
+    }
+}
+
+async fn read_v1<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<SocketAddr>> {
+    let mut header: Vec<u8> = vec![b'P'];
+    while !header.ends_with(b"\r\n") {
+        ensure!(header.len() < V1_MAX_LENGTH, "PROXY v1 header is too long");
+        header.push(reader.read_u8().await?);
+    }
+    ensure!(header.starts_with(V1_PREFIX), "Invalid PROXY v1 header");
+
+    let header: &str = std::str::from_utf8(&header[V1_PREFIX.len()..header.len() - 2])
+        .context("Invalid PROXY v1 header")?;
+    let fields: Vec<&str> = header.split(' ').collect();
+    match fields[..] {
+        ["UNKNOWN", ..] => Ok(None),
+        ["TCP4" | "TCP6", source_address, _, source_port, _] => Ok(Some(SocketAddr::new(
+            source_address
+                .parse()
+                .context("Invalid PROXY v1 source address")?,
+            source_port
+                .parse()
+                .context("Invalid PROXY v1 source port")?,
+        ))),
+        _ => bail!("Invalid PROXY v1 header"),
+    }
+}
+
+async fn read_v2<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<SocketAddr>> {
+    let mut header: [u8; 16] = [0; 16];
+    header[0] = b'\r';
+    reader.read_exact(&mut header[1..]).await?;
+    ensure!(&header[..12] == V2_SIGNATURE, "Invalid PROXY v2 signature");
+    ensure!(header[12] >> 4 == 2, "Unsupported PROXY protocol version");
+
+    let command: u8 = header[12] & 0x0F;
+    let family: u8 = header[13] >> 4;
+    let length: usize = u16::from_be_bytes([header[14], header[15]]) as usize;
+
+    // The addresses may be followed by TLVs, which are skipped
+    let mut addresses: Vec<u8> = vec![0; length];
+    reader.read_exact(&mut addresses).await?;
+
+    match command {
+        // LOCAL, the connection was made by the proxy itself
+        0x0 => return Ok(None),
+        // PROXY
+        0x1 => {}
+        _ => bail!("Unsupported PROXY v2 command"),
+    }
+
+    match family {
+        // AF_INET
+        0x1 => {
+            ensure!(length >= 12, "PROXY v2 header is too short");
+            let ip: [u8; 4] = addresses[0..4].try_into().unwrap();
+            let port: u16 = u16::from_be_bytes([addresses[8], addresses[9]]);
+            Ok(Some(SocketAddr::new(IpAddr::V4(Ipv4Addr::from(ip)), port)))
+        }
+        // AF_INET6
+        0x2 => {
+            ensure!(length >= 36, "PROXY v2 header is too short");
+            let ip: [u8; 16] = addresses[0..16].try_into().unwrap();
+            let port: u16 = u16::from_be_bytes([addresses[32], addresses[33]]);
+            Ok(Some(SocketAddr::new(IpAddr::V6(Ipv6Addr::from(ip)), port)))
+        }
+        // AF_UNSPEC or AF_UNIX, which carry no usable address
+        _ => Ok(None),
+    }
+}
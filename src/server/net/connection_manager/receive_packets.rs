@@ -1,22 +1,42 @@
+use std::{
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
 use eyre::{Context, Result};
 
 use tokio::{
+    io::{copy, sink},
     net::tcp::OwnedReadHalf,
     sync::{mpsc::UnboundedSender, watch},
+    time::timeout,
 };
 
 use crate::server::{
-    net::packets::{IncomingPacket, ReadPacket},
+    net::{
+        encryption::EncryptedReader,
+        packets::{IncomingPacket, ReadPacket},
+        recording::{Direction, Recorder},
+    },
     state::ConnectionState,
 };
 
 use super::types::WrappedIncomingPacket;
 
+/// How long legacy clients have to send the rest of their ping and close the connection.
+const LEGACY_PING_DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub async fn receive_packets(
     conn_id: i32,
-    mut read_half: OwnedReadHalf,
+    mut read_half: EncryptedReader<OwnedReadHalf>,
     mut state_receiver: watch::Receiver<ConnectionState>,
+    compression_threshold: Arc<AtomicI32>,
+    mut shared_secret_receiver: watch::Receiver<Option<[u8; 16]>>,
     incoming_packet_sender: UnboundedSender<WrappedIncomingPacket>,
+    recorder: Option<Recorder>,
 ) -> Result<()> {
     let mut wait_for_state: bool = false;
     let mut state: ConnectionState = *state_receiver.borrow_and_update();
@@ -35,12 +55,55 @@ pub async fn receive_packets(
             state = *state_receiver.borrow_and_update();
         }
 
-        let packet: IncomingPacket = read_half
-            .read_packet(&state)
-            .await
-            .context("Failed to read packet")?;
+        let frame: Vec<u8> = if state == ConnectionState::Handshake {
+            match read_half
+                .read_handshake_frame()
+                .await
+                .context("Failed to read packet")?
+            {
+                Some(frame) => frame,
+                None => {
+                    incoming_packet_sender.send(WrappedIncomingPacket {
+                        conn_id,
+                        packet: IncomingPacket::LegacyServerListPing,
+                    })?;
+                    // The rest of the ping is not needed, but closing the socket with unread
+                    // bytes sends a reset, which can make clients drop the response
+                    let _ =
+                        timeout(LEGACY_PING_DRAIN_TIMEOUT, copy(&mut read_half, &mut sink())).await;
+                    return Ok(());
+                }
+            }
+        } else {
+            read_half
+                .read_frame()
+                .await
+                .context("Failed to read packet")?
+        };
+        if let Some(recorder) = &recorder {
+            recorder.record(Direction::Serverbound, &frame);
+        }
+
+        // The threshold is loaded only after the whole frame has arrived,
+        // since the writer may enable compression while we are waiting for it.
+        let packet: IncomingPacket =
+            IncomingPacket::decode(frame, &state, compression_threshold.load(Ordering::SeqCst))
+                .context("Failed to decode packet")?;
         wait_for_state = packet.can_change_state();
 
+        // Everything the client sends after Encryption Response is encrypted
+        if matches!(packet, IncomingPacket::EncryptionResponse { .. }) {
+            incoming_packet_sender.send(WrappedIncomingPacket { conn_id, packet })?;
+
+            let shared_secret: [u8; 16] = shared_secret_receiver
+                .wait_for(Option::is_some)
+                .await
+                .context("Shared secret channel closed")?
+                .unwrap();
+            read_half.enable_encryption(&shared_secret);
+            continue;
+        }
+
         incoming_packet_sender.send(WrappedIncomingPacket { conn_id, packet })?;
     }
 }
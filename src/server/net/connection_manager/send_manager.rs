@@ -1,28 +1,147 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
 
 use eyre::{eyre, Result};
 use tokio::{
     select,
-    sync::mpsc::{UnboundedReceiver, UnboundedSender},
+    sync::mpsc::{error::TrySendError, Sender, UnboundedReceiver},
+    time::{interval, Interval},
+};
+
+use crate::{
+    config::{SendQueueLimits, SlowClientPolicy},
+    server::net::packets::OutgoingPacket,
 };
 
-use crate::server::net::packets::OutgoingPacket;
+use super::types::{SendHalf, SendQueue, WrappedOutgoingPacket};
+
+/// The packets of a connection on their way to its writer.
+pub struct Queue {
+    outgoing_packet_sender: Sender<OutgoingPacket>,
+    send_queue: Arc<SendQueue>,
+    /// Low-priority packets held back while the client is behind.
+    deferred: VecDeque<OutgoingPacket>,
+}
+impl Queue {
+    pub fn new(outgoing_packet_sender: Sender<OutgoingPacket>, send_queue: Arc<SendQueue>) -> Self {
+        Self {
+            outgoing_packet_sender,
+            send_queue,
+            deferred: VecDeque::new(),
+        }
+    }
+
+    /// Returns `false` if the connection is closed.
+    pub fn send(&mut self, packet: OutgoingPacket, limits: &SendQueueLimits) -> bool {
+        let behind: bool = self.send_queue.depth() >= limits.high_water_mark;
 
-use super::types::{SendHalf, WrappedOutgoingPacket};
+        match packet {
+            // Chunks that were never sent do not have to be unloaded, nor their blocks updated
+            OutgoingPacket::UnloadChunk { chunk_x, chunk_z } => {
+                let deferred: usize = self.deferred.len();
+                self.deferred
+                    .retain(|p| deferred_chunk(p) != Some((chunk_x, chunk_z)));
+                if self.deferred.len() < deferred {
+                    return true;
+                }
+            }
+            // The client drops every chunk of the old world, which must not follow it
+            OutgoingPacket::Respawn(_) => self.deferred.clear(),
+            // A block of a chunk that was never sent is updated once the chunk is
+            OutgoingPacket::BlockUpdate { .. }
+                if self
+                    .deferred
+                    .iter()
+                    .any(|p| deferred_chunk(p) == deferred_chunk(&packet)) =>
+            {
+                return self.defer(packet, limits);
+            }
+            _ => {}
+        }
+
+        if limits.slow_clients == SlowClientPolicy::Defer
+            && packet.is_low_priority()
+            && (behind || !self.deferred.is_empty())
+        {
+            return self.defer(packet, limits);
+        }
+        if limits.slow_clients == SlowClientPolicy::Disconnect && behind {
+            self.send_queue.set_too_slow();
+        }
+
+        self.try_send(packet)
+    }
+
+    /// Sends deferred packets until the client is behind again.
+    pub fn send_deferred(&mut self, limits: &SendQueueLimits) -> bool {
+        while self.send_queue.depth() < limits.high_water_mark {
+            let Some(packet) = self.deferred.pop_front() else {
+                break;
+            };
+            if !self.try_send(packet) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn defer(&mut self, packet: OutgoingPacket, limits: &SendQueueLimits) -> bool {
+        // A client that never catches up is disconnected instead of holding everything
+        if self.deferred.len() >= limits.max_deferred {
+            self.send_queue.set_too_slow();
+            return true;
+        }
+        self.deferred.push_back(packet);
+        true
+    }
+
+    fn try_send(&mut self, packet: OutgoingPacket) -> bool {
+        self.send_queue.push();
+        match self.outgoing_packet_sender.try_send(packet) {
+            Ok(()) => true,
+            // The packet is lost, so the client has to go
+            Err(TrySendError::Full(_)) => {
+                self.send_queue.pop();
+                self.send_queue.set_too_slow();
+                true
+            }
+            Err(TrySendError::Closed(_)) => false,
+        }
+    }
+}
+
+/// The chunk that a packet which can be deferred belongs to.
+fn deferred_chunk(packet: &OutgoingPacket) -> Option<(i32, i32)> {
+    match packet {
+        OutgoingPacket::ChunkDataAndUpdateLight {
+            chunk_x, chunk_z, ..
+        } => Some((*chunk_x, *chunk_z)),
+        OutgoingPacket::BlockUpdate { location, .. } => {
+            Some((location.x.div_euclid(16), location.z.div_euclid(16)))
+        }
+        _ => None,
+    }
+}
 
 pub async fn send_manager(
+    limits: SendQueueLimits,
     mut outgoing_packet_receiver: UnboundedReceiver<WrappedOutgoingPacket>,
     mut send_half_receiver: UnboundedReceiver<SendHalf>,
 ) -> Result<()> {
-    let mut send_halves: HashMap<i32, UnboundedSender<OutgoingPacket>> = HashMap::new();
+    let mut send_halves: HashMap<i32, Queue> = HashMap::new();
+    // Deferred packets are sent as the writers catch up
+    let mut deferred_interval: Interval = interval(Duration::from_millis(50));
 
     loop {
         select! {
             Some(WrappedOutgoingPacket { conn_id, packet }) = outgoing_packet_receiver.recv() => {
                 let mut remove = false;
 
-                if let Some(outgoing_packet_sender) = send_halves.get(&conn_id) {
-                    if packet == OutgoingPacket::Disconnect || outgoing_packet_sender.send(packet).is_err() {
+                if let Some(queue) = send_halves.get_mut(&conn_id) {
+                    if packet == OutgoingPacket::Disconnect || !queue.send(packet, &limits) {
                         remove = true;
                     }
                 }
@@ -32,8 +151,12 @@ pub async fn send_manager(
                 }
             },
 
-            Some(SendHalf { conn_id, outgoing_packet_sender }) = send_half_receiver.recv() => {
-                send_halves.insert(conn_id, outgoing_packet_sender);
+            Some(SendHalf { conn_id, outgoing_packet_sender, send_queue }) = send_half_receiver.recv() => {
+                send_halves.insert(conn_id, Queue::new(outgoing_packet_sender, send_queue));
+            },
+
+            _ = deferred_interval.tick() => {
+                send_halves.retain(|_, queue| queue.send_deferred(&limits));
             },
 
             else => return Err(eyre!("Channel was closed")),
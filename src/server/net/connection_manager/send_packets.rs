@@ -1,17 +1,56 @@
+use std::sync::{
+    atomic::{AtomicI32, Ordering},
+    Arc,
+};
+
 use eyre::{eyre, Context, Result};
-use tokio::{net::tcp::OwnedWriteHalf, sync::mpsc::UnboundedReceiver};
+use tokio::{
+    net::tcp::OwnedWriteHalf,
+    sync::{mpsc::Receiver, watch},
+};
+
+use crate::server::net::{
+    encryption::EncryptedWriter,
+    packets::{OutgoingPacket, WritePacket},
+    recording::{Direction, Recorder},
+};
 
-use crate::server::net::packets::{OutgoingPacket, WritePacket};
+use super::types::SendQueue;
 
 pub async fn send_packets(
-    mut write_half: OwnedWriteHalf,
-    mut outgoing_packet_receiver: UnboundedReceiver<OutgoingPacket>,
+    mut write_half: EncryptedWriter<OwnedWriteHalf>,
+    mut outgoing_packet_receiver: Receiver<OutgoingPacket>,
+    send_queue: Arc<SendQueue>,
+    compression_threshold: Arc<AtomicI32>,
+    shared_secret_sender: watch::Sender<Option<[u8; 16]>>,
+    recorder: Option<Recorder>,
 ) -> Result<()> {
     while let Some(packet) = outgoing_packet_receiver.recv().await {
-        write_half
-            .write_packet(packet)
-            .await
-            .context("Failed to write packet")?;
+        send_queue.pop();
+
+        if let OutgoingPacket::EnableEncryption { shared_secret } = packet {
+            write_half.enable_encryption(&shared_secret);
+            shared_secret_sender.send_replace(Some(shared_secret));
+            continue;
+        }
+
+        let threshold: i32 = compression_threshold.load(Ordering::SeqCst);
+
+        // Set Compression itself is sent uncompressed, but the client answers with compressed packets.
+        // The new threshold is stored before writing, so that the reader never misses it.
+        if let OutgoingPacket::SetCompression { threshold } = packet {
+            compression_threshold.store(threshold, Ordering::SeqCst);
+        }
+
+        match &recorder {
+            Some(recorder) if packet.is_framed() => {
+                let frame: Vec<u8> = packet.encode_frame(threshold)?;
+                recorder.record(Direction::Clientbound, &frame);
+                write_half.write_frame(frame).await
+            }
+            _ => write_half.write_packet(packet, threshold).await,
+        }
+        .context("Failed to write packet")?;
     }
 
     Err(eyre!("Outgoing packet channel closed"))
@@ -0,0 +1,153 @@
+use std::{collections::HashMap, net::IpAddr, time::Duration};
+
+use strum_macros::Display;
+use tokio::time::Instant;
+
+use crate::config::ConnectionLimits;
+
+/// Entries are pruned once a map grows past this size.
+const PRUNE_THRESHOLD: usize = 1024;
+
+#[derive(Display, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Rejection {
+    Banned,
+    TooManyConnections,
+    TooManyConnectionsFromAddress,
+    TooFrequent,
+}
+
+/// Keeps track of connections per IP address and decides which ones to accept.
+pub struct Throttle {
+    limits: ConnectionLimits,
+    /// Whether a proxy forwards the addresses of clients, in which case connections only
+    /// count towards the limits of an address once it is known.
+    forwarded: bool,
+    /// The address each connection counts towards, if any.
+    addresses: HashMap<i32, IpAddr>,
+    connections: HashMap<IpAddr, usize>,
+    total_connections: usize,
+    last_attempts: HashMap<IpAddr, Instant>,
+    handshake_failures: HashMap<IpAddr, u32>,
+    bans: HashMap<IpAddr, Instant>,
+}
+impl Throttle {
+    pub fn new(limits: ConnectionLimits, forwarded: bool) -> Self {
+        Self {
+            limits,
+            forwarded,
+            addresses: HashMap::new(),
+            connections: HashMap::new(),
+            total_connections: 0,
+            last_attempts: HashMap::new(),
+            handshake_failures: HashMap::new(),
+            bans: HashMap::new(),
+        }
+    }
+
+    /// Registers a new connection, unless it has to be rejected.
+    /// Behind a proxy, only bans and the total limit apply until [`Throttle::readdress`].
+    pub fn try_accept(&mut self, conn_id: i32, ip: IpAddr) -> Result<(), Rejection> {
+        self.check_ban(ip)?;
+        if self.limits.max_connections != 0 && self.total_connections >= self.limits.max_connections
+        {
+            return Err(Rejection::TooManyConnections);
+        }
+        if !self.forwarded {
+            self.count(conn_id, ip)?;
+        }
+
+        self.total_connections += 1;
+        Ok(())
+    }
+
+    /// Moves a connection to the client address forwarded by a proxy, unless that address
+    /// has to be rejected. Bans and handshake failures then apply to the client.
+    pub fn readdress(&mut self, conn_id: i32, ip: IpAddr) -> Result<(), Rejection> {
+        self.check_ban(ip)?;
+        self.uncount(conn_id);
+        self.count(conn_id, ip)
+    }
+
+    /// Unregisters a closed connection.
+    /// Addresses that fail the Handshake too many times in a row are banned for a while.
+    pub fn release(&mut self, conn_id: i32, ip: IpAddr, handshake_failed: bool) {
+        let ip: IpAddr = self.uncount(conn_id).unwrap_or(ip);
+        self.total_connections -= 1;
+
+        if !handshake_failed {
+            self.handshake_failures.remove(&ip);
+            return;
+        }
+        if self.limits.max_handshake_failures == 0 {
+            return;
+        }
+
+        let failures: &mut u32 = self.handshake_failures.entry(ip).or_default();
+        *failures += 1;
+        if *failures >= self.limits.max_handshake_failures {
+            self.handshake_failures.remove(&ip);
+            self.bans.insert(
+                ip,
+                Instant::now() + Duration::from_secs(self.limits.ban_duration),
+            );
+
+            if self.bans.len() > PRUNE_THRESHOLD {
+                let now: Instant = Instant::now();
+                self.bans.retain(|_, &mut until| now < until);
+            }
+        }
+    }
+
+    fn check_ban(&mut self, ip: IpAddr) -> Result<(), Rejection> {
+        if let Some(&until) = self.bans.get(&ip) {
+            if Instant::now() < until {
+                return Err(Rejection::Banned);
+            }
+            self.bans.remove(&ip);
+        }
+        Ok(())
+    }
+
+    /// Counts a connection towards the limits of an address.
+    fn count(&mut self, conn_id: i32, ip: IpAddr) -> Result<(), Rejection> {
+        let now: Instant = Instant::now();
+
+        // Every attempt counts, even a rejected one
+        let last_attempt: Option<Instant> = self.last_attempts.insert(ip, now);
+        if self.last_attempts.len() > PRUNE_THRESHOLD {
+            let interval: Duration = self.min_interval();
+            self.last_attempts
+                .retain(|_, &mut t| now.duration_since(t) < interval);
+        }
+        if last_attempt.is_some_and(|t| now.duration_since(t) < self.min_interval()) {
+            return Err(Rejection::TooFrequent);
+        }
+
+        let connections: &mut usize = self.connections.entry(ip).or_default();
+        if self.limits.max_connections_per_ip != 0
+            && *connections >= self.limits.max_connections_per_ip
+        {
+            return Err(Rejection::TooManyConnectionsFromAddress);
+        }
+
+        *connections += 1;
+        self.addresses.insert(conn_id, ip);
+        Ok(())
+    }
+
+    /// Returns the address the connection counted towards.
+    fn uncount(&mut self, conn_id: i32) -> Option<IpAddr> {
+        let ip: IpAddr = self.addresses.remove(&conn_id)?;
+        if let Some(connections) = self.connections.get_mut(&ip) {
+            *connections -= 1;
+            if *connections == 0 {
+                self.connections.remove(&ip);
+            }
+        }
+        Some(ip)
+    }
+
+    fn min_interval(&self) -> Duration {
+        Duration::from_millis(self.limits.min_connection_interval)
+    }
+}
@@ -1,13 +1,24 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
 use tokio::{
-    sync::{mpsc::UnboundedSender, watch},
+    sync::{mpsc::Sender, watch},
     time::Instant,
 };
 
 use crate::server::{
+    forwarding::ForwardedPlayer,
     net::packets::{IncomingPacket, OutgoingPacket},
     state::ConnectionState,
 };
 
+use super::throttle::{Rejection, Throttle};
+
 pub struct WrappedIncomingPacket {
     pub conn_id: i32,
     pub packet: IncomingPacket,
@@ -23,6 +34,14 @@ pub struct WrappedConnectionState {
     pub state: ConnectionState,
 }
 
+/// A newly accepted client. The address may have been passed on by a proxy.
+pub struct WrappedConnection {
+    pub conn_id: i32,
+    pub address: SocketAddr,
+    pub send_queue: Arc<SendQueue>,
+    pub throttle: Arc<Mutex<Throttle>>,
+}
+
 pub struct ReceiveHalf {
     pub conn_id: i32,
     pub state_sender: watch::Sender<ConnectionState>,
@@ -30,29 +49,77 @@ pub struct ReceiveHalf {
 
 pub struct SendHalf {
     pub conn_id: i32,
-    pub outgoing_packet_sender: UnboundedSender<OutgoingPacket>,
+    pub outgoing_packet_sender: Sender<OutgoingPacket>,
+    pub send_queue: Arc<SendQueue>,
+}
+
+/// Keeps track of the packets waiting to be written to a connection.
+/// Shared by the send manager, the writer and the server.
+#[derive(Default)]
+pub struct SendQueue {
+    depth: AtomicUsize,
+    too_slow: AtomicBool,
+}
+impl SendQueue {
+    /// The number of packets waiting to be written, not counting deferred ones.
+    pub fn depth(&self) -> usize {
+        self.depth.load(Ordering::SeqCst)
+    }
+
+    /// Whether the client cannot keep up and should be disconnected.
+    pub fn is_too_slow(&self) -> bool {
+        self.too_slow.load(Ordering::SeqCst)
+    }
+
+    pub(super) fn push(&self) {
+        self.depth.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn pop(&self) {
+        self.depth.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    pub(super) fn set_too_slow(&self) {
+        self.too_slow.store(true, Ordering::SeqCst);
+    }
 }
 
 pub struct Connection {
+    pub address: SocketAddr,
+    pub send_queue: Arc<SendQueue>,
+    /// Shared with the listener, which throttles connections by address.
+    pub throttle: Arc<Mutex<Throttle>>,
     pub last_timeout: Instant,
     pub state: ConnectionState,
+    /// Set between Login Start and Encryption Response in online mode.
+    pub username: Option<String>,
+    pub verify_token: [u8; 4],
+    /// Set by the Handshake when BungeeCord forwarding is used.
+    pub forwarded: Option<ForwardedPlayer>,
 }
 impl Connection {
-    pub fn new() -> Self {
-        Self::default()
-    }
-    // pub fn from_state(state: ConnectionState) -> Self {
-    //     Self {
-    //         last_timeout: Instant::now(),
-    //         state,
-    //     }
-    // }
-}
-impl Default for Connection {
-    fn default() -> Self {
+    pub fn new(
+        address: SocketAddr,
+        send_queue: Arc<SendQueue>,
+        throttle: Arc<Mutex<Throttle>>,
+    ) -> Self {
         Self {
+            address,
+            send_queue,
+            throttle,
             last_timeout: Instant::now(),
             state: ConnectionState::default(),
+            username: None,
+            verify_token: [0; 4],
+            forwarded: None,
         }
     }
+
+    /// Replaces the address of the client with the one forwarded by a proxy,
+    /// unless connections from it are throttled or banned.
+    pub fn set_forwarded_ip(&mut self, conn_id: i32, ip: IpAddr) -> Result<(), Rejection> {
+        self.throttle.lock().unwrap().readdress(conn_id, ip)?;
+        self.address.set_ip(ip);
+        Ok(())
+    }
 }
@@ -0,0 +1,147 @@
+use std::{
+    io,
+    pin::Pin,
+    slice,
+    task::{ready, Context, Poll},
+};
+
+use aes::{
+    cipher::{generic_array::GenericArray, BlockDecryptMut, BlockEncryptMut, KeyIvInit},
+    Aes128,
+};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+type Aes128Cfb8Enc = cfb8::Encryptor<Aes128>;
+type Aes128Cfb8Dec = cfb8::Decryptor<Aes128>;
+
+/// The most bytes encrypted ahead of what `inner` has accepted.
+const MAX_BUFFERED: usize = 16 * 1024;
+
+/// Decrypts everything read from `inner` once encryption is enabled.
+pub struct EncryptedReader<R> {
+    inner: R,
+    cipher: Option<Aes128Cfb8Dec>,
+}
+impl<R> EncryptedReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            cipher: None,
+        }
+    }
+
+    /// The shared secret is used as both the key and the IV.
+    pub fn enable_encryption(&mut self, shared_secret: &[u8; 16]) {
+        self.cipher = Some(Aes128Cfb8Dec::new(
+            shared_secret.into(),
+            shared_secret.into(),
+        ));
+    }
+}
+impl<R: AsyncRead + Unpin> AsyncRead for EncryptedReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this: &mut Self = self.get_mut();
+        let filled: usize = buf.filled().len();
+
+        ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+
+        if let Some(cipher) = &mut this.cipher {
+            for byte in &mut buf.filled_mut()[filled..] {
+                cipher.decrypt_block_mut(GenericArray::from_mut_slice(slice::from_mut(byte)));
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Encrypts everything written to `inner` once encryption is enabled.
+///
+/// Encrypted bytes are buffered until `inner` accepts them, since the cipher state
+/// cannot be rewound after a partial write. New bytes are only accepted once the buffer
+/// has been written out, so a slow `inner` holds writers back. Call `flush` after each packet.
+pub struct EncryptedWriter<W> {
+    inner: W,
+    cipher: Option<Aes128Cfb8Enc>,
+    buf: Vec<u8>,
+    pos: usize,
+}
+impl<W> EncryptedWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            cipher: None,
+            buf: vec![],
+            pos: 0,
+        }
+    }
+
+    /// The shared secret is used as both the key and the IV.
+    pub fn enable_encryption(&mut self, shared_secret: &[u8; 16]) {
+        self.cipher = Some(Aes128Cfb8Enc::new(
+            shared_secret.into(),
+            shared_secret.into(),
+        ));
+    }
+}
+impl<W: AsyncWrite + Unpin> EncryptedWriter<W> {
+    fn poll_write_buffered(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.pos < self.buf.len() {
+            let n: usize = ready!(Pin::new(&mut self.inner).poll_write(cx, &self.buf[self.pos..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.pos += n;
+        }
+        self.buf.clear();
+        self.pos = 0;
+
+        Poll::Ready(Ok(()))
+    }
+}
+impl<W: AsyncWrite + Unpin> AsyncWrite for EncryptedWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this: &mut Self = self.get_mut();
+
+        // Bytes from the previous write must go out first
+        if this.cipher.is_some() {
+            ready!(this.poll_write_buffered(cx))?;
+        }
+        let Some(cipher) = &mut this.cipher else {
+            return Pin::new(&mut this.inner).poll_write(cx, buf);
+        };
+
+        let buf: &[u8] = &buf[..buf.len().min(MAX_BUFFERED)];
+        this.buf.extend_from_slice(buf);
+        for byte in &mut this.buf {
+            cipher.encrypt_block_mut(GenericArray::from_mut_slice(slice::from_mut(byte)));
+        }
+
+        // The bytes are encrypted and buffered at this point, so a pending write is not an error
+        if let Poll::Ready(Err(e)) = this.poll_write_buffered(cx) {
+            return Poll::Ready(Err(e));
+        }
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this: &mut Self = self.get_mut();
+        ready!(this.poll_write_buffered(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this: &mut Self = self.get_mut();
+        ready!(this.poll_write_buffered(cx))?;
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
@@ -1,5 +1,7 @@
 pub mod connection_manager;
+pub mod encryption;
 pub mod packets;
+pub mod recording;
 
 pub use connection_manager::types::*;
 pub use packets::*;
@@ -1,7 +1,10 @@
 use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::sync::Arc;
 
-use eyre::Result;
+use eyre::{ensure, eyre, Result};
 use fastnbt::Value;
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
 use log::debug;
 use strum_macros::Display;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
@@ -9,16 +12,26 @@ use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use crate::server::{
     state::ConnectionState,
     types::{
-        self, Angle, AsyncReadVarInt, Chunk, EntityMetadata, Gamemode, InteractionType,
-        PlayerInfoUpdateActions, Position, ReadString, ReadVarInt, Uuid, WriteString, WriteVarInt,
+        self, Angle, AsyncReadVarInt, BlockFace, Chunk, DiggingStatus, EntityMetadata,
+        EntityMetadataField, Gamemode, InteractionType, PlayerInfoUpdateActions, Position,
+        Property, ReadString, ReadVarInt, ReadVarLong, Uuid, VarInt, WriteString, WriteVarInt,
     },
-    util::ReadExt,
+    util::{ReadError, ReadExt},
+    world::light::LightData,
 };
 
 type Slot = Option<(i32, i8, Value)>;
+type AttributeProperty = (String, f64, Vec<(Uuid, f64, u8)>);
+
+/// The first byte sent by pre-1.7 clients when pinging, which cannot start a valid Handshake.
+pub const LEGACY_SERVER_LIST_PING: u8 = 0xFE;
+/// The largest packet the client is allowed to send (2^21 - 1, the largest 3-byte VarInt).
+pub const MAX_PACKET_LENGTH: i32 = 2097151;
+/// The largest uncompressed packet the client is allowed to send.
+pub const MAX_DATA_LENGTH: i32 = 8388608;
 
 #[allow(dead_code)]
-#[derive(Display)]
+#[derive(Display, Debug)]
 pub enum IncomingPacket {
     // TODO: Disconnect
     Unknown {
@@ -31,8 +44,8 @@ pub enum IncomingPacket {
         server_port: u16,
         next_state: i32,
     },
-    // /// Packet ID: 0xFE
-    // LegacyServerListPing {},
+    /// Packet ID: 0xFE
+    LegacyServerListPing,
     /// Packet ID: 0x00
     StatusRequest,
     /// Packet ID: 0x01
@@ -44,6 +57,22 @@ pub enum IncomingPacket {
         name: String,
         player_uuid: Option<u128>,
     },
+    /// Packet ID: 0x01
+    EncryptionResponse {
+        shared_secret: Vec<u8>,
+        verify_token: Vec<u8>,
+    },
+    /// Packet ID: 0x02
+    LoginPluginResponse {
+        message_id: i32,
+        /// `None` if the client did not understand the request.
+        data: Option<Vec<u8>>,
+    },
+    /// Packet ID: 0x04
+    ChatCommand {
+        /// Without the leading slash. Argument signatures are not read.
+        command: String,
+    },
     /// Packet ID: 0x10
     Interact {
         entity_id: i32,
@@ -82,16 +111,43 @@ pub enum IncomingPacket {
     SetPlayerOnGround {
         on_ground: bool,
     },
+    /// Packet ID: 0x1D
+    PlayerAction {
+        status: DiggingStatus,
+        location: Position,
+        face: BlockFace,
+        sequence: i32,
+    },
     /// Packet ID: 0x1E
     PlayerCommand {
         entity_id: i32,
         action_id: i32,
         jump_boost: i32,
     },
+    /// Packet ID: 0x28
+    SetHeldItem {
+        slot: i16,
+    },
+    /// Packet ID: 0x2B
+    SetCreativeModeSlot {
+        slot: i16,
+        clicked_item: Slot,
+    },
     /// Packet ID: 0x2F
     SwingArm {
         hand: i32,
     },
+    /// Packet ID: 0x31
+    UseItemOn {
+        hand: i32,
+        location: Position,
+        face: BlockFace,
+        cursor_x: f32,
+        cursor_y: f32,
+        cursor_z: f32,
+        inside_block: bool,
+        sequence: i32,
+    },
     /// Packet ID: 0x32
     UseItem {
         hand: i32,
@@ -102,9 +158,11 @@ impl IncomingPacket {
     pub fn sent_state(&self) -> ConnectionState {
         use IncomingPacket::*;
         match self {
-            Handshake { .. } => ConnectionState::Handshake,
-            StatusRequest { .. } | PingRequest { .. } => ConnectionState::Status,
-            LoginStart { .. } => ConnectionState::Login,
+            Handshake { .. } | LegacyServerListPing => ConnectionState::Handshake,
+            StatusRequest | PingRequest { .. } => ConnectionState::Status,
+            LoginStart { .. } | EncryptionResponse { .. } | LoginPluginResponse { .. } => {
+                ConnectionState::Login
+            }
             _ => ConnectionState::Play,
         }
     }
@@ -113,18 +171,58 @@ impl IncomingPacket {
         use IncomingPacket::*;
         matches!(
             self,
-            Handshake { .. } | PingRequest { .. } | LoginStart { .. }
+            Handshake { .. }
+                | LegacyServerListPing
+                | PingRequest { .. }
+                | LoginStart { .. }
+                | EncryptionResponse { .. }
+                | LoginPluginResponse { .. }
         )
     }
 }
 pub trait ReadPacket: AsyncRead + Unpin + Sized {
-    async fn read_packet(&mut self, state: &ConnectionState) -> Result<IncomingPacket> {
+    /// Reads a single length-prefixed frame, without decoding it.
+    async fn read_frame(&mut self) -> Result<Vec<u8>> {
         let length: i32 = self.async_read_varint().await?;
-        if length == 0 {
+        ensure!(
+            (0..=MAX_PACKET_LENGTH).contains(&length),
+            "Invalid packet length {length}"
+        );
+        let mut buf: Vec<u8> = vec![0; length as usize];
+        self.read_exact(&mut buf).await?;
+        Ok(buf)
+    }
+
+    /// Reads a frame in the Handshake state, where legacy clients send 0xFE instead.
+    /// Returns `None` for a legacy server list ping.
+    async fn read_handshake_frame(&mut self) -> Result<Option<Vec<u8>>> {
+        let first_byte: u8 = self.read_u8().await?;
+        if first_byte == LEGACY_SERVER_LIST_PING {
+            return Ok(None);
+        }
+        AsyncReadExt::chain([first_byte].as_slice(), &mut *self)
+            .read_frame()
+            .await
+            .map(Some)
+    }
+}
+impl<T: AsyncRead + Unpin> ReadPacket for T {}
+
+impl IncomingPacket {
+    /// Decodes a frame read by [`ReadPacket::read_frame`].
+    /// A negative `compression_threshold` means that compression is disabled.
+    pub fn decode(
+        frame: Vec<u8>,
+        state: &ConnectionState,
+        compression_threshold: i32,
+    ) -> Result<Self> {
+        if frame.is_empty() {
             return Ok(IncomingPacket::Unknown { data: vec![] });
         }
-        let mut buf: VecDeque<u8> = VecDeque::from(vec![0; length as usize]);
-        self.read_exact(buf.as_mut_slices().0).await?;
+        let mut buf: VecDeque<u8> = VecDeque::from(frame);
+        if compression_threshold >= 0 {
+            buf = decompress(buf, compression_threshold)?;
+        }
         let id: i32 = buf.read_varint()?;
 
         use IncomingPacket::*;
@@ -151,6 +249,21 @@ pub trait ReadPacket: AsyncRead + Unpin + Sized {
                     },
                 }
             }
+            (ConnectionState::Login, 0x01) => EncryptionResponse {
+                shared_secret: read_byte_array(&mut buf)?,
+                verify_token: read_byte_array(&mut buf)?,
+            },
+            (ConnectionState::Login, 0x02) => {
+                let message_id: i32 = buf.read_varint()?;
+                let successful: bool = buf.read_u8()? != 0;
+                LoginPluginResponse {
+                    message_id,
+                    data: successful.then(|| Vec::from(buf)),
+                }
+            }
+            (ConnectionState::Play, 0x04) => ChatCommand {
+                command: buf.read_string()?,
+            },
             (ConnectionState::Play, 0x10) => {
                 let entity_id: i32 = buf.read_varint()?;
                 let interaction_type: InteractionType = match buf.read_varint()? {
@@ -207,14 +320,50 @@ pub trait ReadPacket: AsyncRead + Unpin + Sized {
             (ConnectionState::Play, 0x17) => SetPlayerOnGround {
                 on_ground: buf.read_u8()? != 0,
             },
+            (ConnectionState::Play, 0x1D) => {
+                let status: i32 = buf.read_varint()?;
+                let location: Position = Position::from_i64(buf.read_i64()?);
+                let face: i8 = buf.read_i8()?;
+                PlayerAction {
+                    status: DiggingStatus::from_id(status)
+                        .ok_or_else(|| eyre!("Invalid digging status {status}"))?,
+                    location,
+                    face: BlockFace::from_id(face as i32)
+                        .ok_or_else(|| eyre!("Invalid block face {face}"))?,
+                    sequence: buf.read_varint()?,
+                }
+            }
             (ConnectionState::Play, 0x1E) => PlayerCommand {
                 entity_id: buf.read_varint()?,
                 action_id: buf.read_varint()?,
                 jump_boost: buf.read_varint()?,
             },
+            (ConnectionState::Play, 0x28) => SetHeldItem {
+                slot: buf.read_i16()?,
+            },
+            (ConnectionState::Play, 0x2B) => SetCreativeModeSlot {
+                slot: buf.read_i16()?,
+                clicked_item: read_slot(&mut buf)?,
+            },
             (ConnectionState::Play, 0x2F) => SwingArm {
                 hand: buf.read_varint()?,
             },
+            (ConnectionState::Play, 0x31) => {
+                let hand: i32 = buf.read_varint()?;
+                let location: Position = Position::from_i64(buf.read_i64()?);
+                let face: i32 = buf.read_varint()?;
+                UseItemOn {
+                    hand,
+                    location,
+                    face: BlockFace::from_id(face)
+                        .ok_or_else(|| eyre!("Invalid block face {face}"))?,
+                    cursor_x: buf.read_f32()?,
+                    cursor_y: buf.read_f32()?,
+                    cursor_z: buf.read_f32()?,
+                    inside_block: buf.read_u8()? != 0,
+                    sequence: buf.read_varint()?,
+                }
+            }
             (ConnectionState::Play, 0x32) => UseItem {
                 hand: buf.read_varint()?,
                 sequence: buf.read_varint()?,
@@ -228,13 +377,61 @@ pub trait ReadPacket: AsyncRead + Unpin + Sized {
         })
     }
 }
-impl<T: AsyncRead + Unpin> ReadPacket for T {}
+
+/// The fields of [`OutgoingPacket::LoginPlay`].
+#[derive(Debug, PartialEq)]
+pub struct LoginPlayData {
+    pub entity_id: i32,
+    pub is_hardcore: bool,
+    pub gamemode: Gamemode,
+    pub previous_gamemode: Option<Gamemode>,
+    pub dimension_names: Vec<String>,
+    pub registry_codec: Vec<u8>,
+    pub dimension_type: String,
+    pub dimension_name: String,
+    pub hashed_seed: i64,
+    pub max_players: i32,
+    pub view_distance: i32,
+    pub simulation_distance: i32,
+    pub reduced_debug_info: bool,
+    pub enable_respawn_screen: bool,
+    pub is_debug: bool,
+    pub is_flat: bool,
+    pub death_location: Option<(String, Position)>,
+    pub portal_cooldown: i32,
+}
+
+/// The fields of [`OutgoingPacket::Respawn`].
+#[derive(Debug, PartialEq)]
+pub struct RespawnData {
+    pub dimension_type: String,
+    pub dimension_name: String,
+    pub hashed_seed: i64,
+    pub gamemode: Gamemode,
+    pub previous_gamemode: Option<Gamemode>,
+    pub is_debug: bool,
+    pub is_flat: bool,
+    /// 0x01 keeps the attributes of the player, 0x02 its metadata.
+    pub data_kept: u8,
+    pub death_location: Option<(String, Position)>,
+    pub portal_cooldown: i32,
+}
 
 #[derive(Display, Debug, PartialEq)]
 #[allow(dead_code)]
 pub enum OutgoingPacket {
     /// This packet is used internally to indicate that the connection should be closed.
     Disconnect,
+    /// This packet is used internally to enable encryption for both halves of the connection.
+    EnableEncryption { shared_secret: [u8; 16] },
+    /// Packet ID: 0xFF, sent unframed
+    LegacyServerListPingResponse {
+        protocol_version: i32,
+        server_version: String,
+        motd: String,
+        online: i32,
+        max: i32,
+    },
     /// Packet ID: 0x00
     StatusResponse { json_response: String },
     /// Packet ID: 0x00
@@ -259,8 +456,20 @@ pub enum OutgoingPacket {
         velocity_y: u16,
         velocity_z: u16,
     },
+    /// Packet ID: 0x01
+    EncryptionRequest {
+        server_id: String,
+        public_key: Vec<u8>,
+        verify_token: Vec<u8>,
+    },
     /// Packet ID: 0x02
-    LoginSuccess { uuid: Uuid, username: String },
+    LoginSuccess {
+        uuid: Uuid,
+        username: String,
+        properties: Vec<Property>,
+    },
+    /// Packet ID: 0x03
+    SetCompression { threshold: i32 },
     /// Packet ID: 0x03
     SpawnPlayer {
         entity_id: i32,
@@ -271,6 +480,16 @@ pub enum OutgoingPacket {
         yaw: f32,
         pitch: f32,
     },
+    /// Packet ID: 0x04
+    LoginPluginRequest {
+        message_id: i32,
+        channel: String,
+        data: Vec<u8>,
+    },
+    /// Packet ID: 0x06
+    AcknowledgeBlockChange { sequence_id: i32 },
+    /// Packet ID: 0x0A
+    BlockUpdate { location: Position, block_id: i32 },
     /// Packet ID: 0x12
     SetContainerContent {
         window_id: u8,
@@ -285,6 +504,8 @@ pub enum OutgoingPacket {
         slot: i16,
         slot_data: Slot,
     },
+    /// Packet ID: 0x1A
+    DisconnectPlay { reason: String },
     /// Packet ID: 0x1B
     DisguisedChatMessage {
         message: String,
@@ -292,43 +513,20 @@ pub enum OutgoingPacket {
         chat_type_name: String,
         target_name: Option<String>,
     },
+    /// Packet ID: 0x1E
+    UnloadChunk { chunk_x: i32, chunk_z: i32 },
     /// Packet ID: 0x23
     KeepAlive { keep_alive_id: i64 },
     /// Packet ID: 0x24
     ChunkDataAndUpdateLight {
         chunk_x: i32,
         chunk_z: i32,
-        heightmaps: Value,
-        data: Chunk,
-        block_entities: Vec<(u8, u16, i32, Value)>,
-        sky_light_mask: Vec<i64>,
-        block_light_mask: Vec<i64>,
-        empty_sky_light_mask: Vec<i64>,
-        empty_block_light_mask: Vec<i64>,
-        sky_light_arrays: Vec<[u8; 1024]>,
-        block_light_arrays: Vec<[u8; 1024]>,
+        /// Everything after the coordinates, from [`encode_chunk_data`].
+        /// Shared by every player the chunk is sent to.
+        data: Arc<Vec<u8>>,
     },
     /// Packet ID: 0x28
-    LoginPlay {
-        entity_id: i32,
-        is_hardcore: bool,
-        gamemode: Gamemode,
-        previous_gamemode: Option<Gamemode>,
-        dimension_names: Vec<String>,
-        registry_codec: Vec<u8>,
-        dimension_type: String,
-        dimension_name: String,
-        hashed_seed: i64,
-        max_players: i32,
-        view_distance: i32,
-        simulation_distance: i32,
-        reduced_debug_info: bool,
-        enable_respawn_screen: bool,
-        is_debug: bool,
-        is_flat: bool,
-        death_location: Option<(String, Position)>,
-        portal_cooldown: i32,
-    },
+    LoginPlay(Box<LoginPlayData>),
     /// Packet ID: 0x2B
     UpdateEntityPosition {
         entity_id: i32,
@@ -360,6 +558,8 @@ pub enum OutgoingPacket {
         window_type: i32,
         window_title: String,
     },
+    /// Packet ID: 0x39
+    PlayerInfoRemove { players: Vec<Uuid> },
     /// Packet ID: 0x3A
     PlayerInfoUpdate {
         actions: u8,
@@ -377,6 +577,8 @@ pub enum OutgoingPacket {
     },
     /// Packet ID: 0x3E
     RemoveEntities { entity_ids: Vec<i32> },
+    /// Packet ID: 0x41
+    Respawn(Box<RespawnData>),
     /// Packet ID: 0x42
     SetHeadRotation { entity_id: i32, head_yaw: f32 },
     /// Packet ID: 0x4E
@@ -393,12 +595,14 @@ pub enum OutgoingPacket {
         entity_id: i32,
         equipment: Vec<(u8, Slot)>,
     },
+    /// Packet ID: 0x64
+    SystemChatMessage { content: String, overlay: bool },
     /// Packet ID: 0x65
     SetTabListHeaderAndFooter { header: String, footer: String },
     /// Packet ID: 0x6A
     UpdateAttributes {
         entity_id: i32,
-        properties: Vec<(String, f64, Vec<(Uuid, f64, u8)>)>,
+        properties: Vec<AttributeProperty>,
     },
     /// Packet ID: 0x6C
     EntityEffect {
@@ -410,15 +614,352 @@ pub enum OutgoingPacket {
         factor_codec: Option<Value>,
     },
 }
-pub trait WritePacket: AsyncWrite + Unpin + Sized {
-    async fn write_packet(&mut self, packet: OutgoingPacket) -> Result<()> {
+impl OutgoingPacket {
+    /// Packets that may be held back while a client is falling behind.
+    pub fn is_low_priority(&self) -> bool {
+        matches!(self, OutgoingPacket::ChunkDataAndUpdateLight { .. })
+    }
+
+    /// Whether the packet is sent as a regular frame.
+    /// Internal packets write nothing, and the legacy server list ping response is written as is.
+    pub fn is_framed(&self) -> bool {
         use OutgoingPacket::*;
-        let (id, mut data) = match packet {
-            Disconnect => return Ok(()),
+        !matches!(
+            self,
+            Disconnect | EnableEncryption { .. } | LegacyServerListPingResponse { .. }
+        )
+    }
+
+    /// Decodes a frame written by [`OutgoingPacket::encode_frame`], to replay recordings.
+    /// A negative `compression_threshold` means that compression is disabled.
+    pub fn decode(
+        frame: Vec<u8>,
+        state: &ConnectionState,
+        compression_threshold: i32,
+    ) -> Result<Self> {
+        let mut buf: VecDeque<u8> = VecDeque::from(frame);
+        if compression_threshold >= 0 {
+            buf = decompress(buf, compression_threshold)?;
+        }
+        let id: i32 = buf.read_varint()?;
+
+        use OutgoingPacket::*;
+        let packet: Self = match (state, id) {
+            (ConnectionState::Status, 0x00) => StatusResponse {
+                json_response: buf.read_string()?,
+            },
+            (ConnectionState::Status, 0x01) => PingResponse {
+                payload: buf.read_i64()?,
+            },
+            (ConnectionState::Login, 0x00) => DisconnectLogin {
+                reason: buf.read_string()?,
+            },
+            (ConnectionState::Login, 0x01) => EncryptionRequest {
+                server_id: buf.read_string()?,
+                public_key: read_byte_array(&mut buf)?,
+                verify_token: read_byte_array(&mut buf)?,
+            },
+            (ConnectionState::Login, 0x02) => {
+                let uuid: Uuid = Uuid(buf.read_u128()?);
+                let PlayerInfoUpdateActions::AddPlayer { name, properties } =
+                    read_player_info_actions(&mut buf, 0x01)?.remove(0)
+                else {
+                    unreachable!()
+                };
+                LoginSuccess {
+                    uuid,
+                    username: name,
+                    properties,
+                }
+            }
+            (ConnectionState::Login, 0x03) => SetCompression {
+                threshold: buf.read_varint()?,
+            },
+            (ConnectionState::Login, 0x04) => LoginPluginRequest {
+                message_id: buf.read_varint()?,
+                channel: buf.read_string()?,
+                data: Vec::from(std::mem::take(&mut buf)),
+            },
+            (ConnectionState::Play, 0x00) => BundleDelimiter,
+            (ConnectionState::Play, 0x01) => SpawnEntity {
+                entity_id: buf.read_varint()?,
+                entity_uuid: Uuid(buf.read_u128()?),
+                entity_type: buf.read_varint()?,
+                x: buf.read_f64()?,
+                y: buf.read_f64()?,
+                z: buf.read_f64()?,
+                pitch: read_angle(&mut buf)?,
+                yaw: read_angle(&mut buf)?,
+                head_yaw: read_angle(&mut buf)?,
+                data: buf.read_varint()?,
+                velocity_x: buf.read_u16()?,
+                velocity_y: buf.read_u16()?,
+                velocity_z: buf.read_u16()?,
+            },
+            (ConnectionState::Play, 0x03) => SpawnPlayer {
+                entity_id: buf.read_varint()?,
+                player_uuid: Uuid(buf.read_u128()?),
+                x: buf.read_f64()?,
+                y: buf.read_f64()?,
+                z: buf.read_f64()?,
+                yaw: read_angle(&mut buf)?,
+                pitch: read_angle(&mut buf)?,
+            },
+            (ConnectionState::Play, 0x06) => AcknowledgeBlockChange {
+                sequence_id: buf.read_varint()?,
+            },
+            (ConnectionState::Play, 0x0A) => BlockUpdate {
+                location: Position::from_i64(buf.read_i64()?),
+                block_id: buf.read_varint()?,
+            },
+            (ConnectionState::Play, 0x12) => {
+                let window_id: u8 = buf.read_u8()?;
+                let state_id: i32 = buf.read_varint()?;
+                let count: i32 = buf.read_varint()?;
+                let slot_data: Vec<Slot> = (0..count)
+                    .map(|_| read_slot(&mut buf))
+                    .collect::<Result<_>>()?;
+                SetContainerContent {
+                    window_id,
+                    state_id,
+                    slot_data,
+                    carried_item: read_slot(&mut buf)?,
+                }
+            }
+            (ConnectionState::Play, 0x14) => SetContainerSlot {
+                window_id: buf.read_u8()?,
+                state_id: buf.read_varint()?,
+                slot: buf.read_i16()?,
+                slot_data: read_slot(&mut buf)?,
+            },
+            (ConnectionState::Play, 0x1A) => DisconnectPlay {
+                reason: buf.read_string()?,
+            },
+            (ConnectionState::Play, 0x1B) => DisguisedChatMessage {
+                message: buf.read_string()?,
+                chat_type: buf.read_varint()?,
+                chat_type_name: buf.read_string()?,
+                target_name: read_optional(&mut buf, |buf| buf.read_string())?,
+            },
+            (ConnectionState::Play, 0x1E) => UnloadChunk {
+                chunk_x: buf.read_i32()?,
+                chunk_z: buf.read_i32()?,
+            },
+            (ConnectionState::Play, 0x23) => KeepAlive {
+                keep_alive_id: buf.read_i64()?,
+            },
+            (ConnectionState::Play, 0x24) => ChunkDataAndUpdateLight {
+                chunk_x: buf.read_i32()?,
+                chunk_z: buf.read_i32()?,
+                data: Arc::new(Vec::from(std::mem::take(&mut buf))),
+            },
+            (ConnectionState::Play, 0x28) => {
+                let entity_id: i32 = buf.read_i32()?;
+                let is_hardcore: bool = buf.read_u8()? != 0;
+                let gamemode: Gamemode = read_gamemode(&mut buf)?;
+                let previous_gamemode: Option<Gamemode> = read_previous_gamemode(&mut buf)?;
+                let count: i32 = buf.read_varint()?;
+                let dimension_names: Vec<String> = (0..count)
+                    .map(|_| buf.read_string())
+                    .collect::<Result<_>>()?;
+                // The codec is kept as NBT, like it is sent
+                let registry_codec: Vec<u8> = {
+                    let mut rest: VecDeque<u8> = buf.clone();
+                    let _: Value = fastnbt::from_reader(&mut rest)?;
+                    buf.drain(..buf.len() - rest.len()).collect()
+                };
+                LoginPlay(Box::new(LoginPlayData {
+                    entity_id,
+                    is_hardcore,
+                    gamemode,
+                    previous_gamemode,
+                    dimension_names,
+                    registry_codec,
+                    dimension_type: buf.read_string()?,
+                    dimension_name: buf.read_string()?,
+                    hashed_seed: buf.read_i64()?,
+                    max_players: buf.read_varint()?,
+                    view_distance: buf.read_varint()?,
+                    simulation_distance: buf.read_varint()?,
+                    reduced_debug_info: buf.read_u8()? != 0,
+                    enable_respawn_screen: buf.read_u8()? != 0,
+                    is_debug: buf.read_u8()? != 0,
+                    is_flat: buf.read_u8()? != 0,
+                    death_location: read_optional(&mut buf, read_global_position)?,
+                    portal_cooldown: buf.read_varint()?,
+                }))
+            }
+            (ConnectionState::Play, 0x2B) => UpdateEntityPosition {
+                entity_id: buf.read_varint()?,
+                dx: buf.read_i16()?,
+                dy: buf.read_i16()?,
+                dz: buf.read_i16()?,
+                on_ground: buf.read_u8()? != 0,
+            },
+            (ConnectionState::Play, 0x2C) => UpdateEntityPositionAndRotation {
+                entity_id: buf.read_varint()?,
+                dx: buf.read_i16()?,
+                dy: buf.read_i16()?,
+                dz: buf.read_i16()?,
+                yaw: read_angle(&mut buf)?,
+                pitch: read_angle(&mut buf)?,
+                on_ground: buf.read_u8()? != 0,
+            },
+            (ConnectionState::Play, 0x2D) => UpdateEntityRotation {
+                entity_id: buf.read_varint()?,
+                yaw: read_angle(&mut buf)?,
+                pitch: read_angle(&mut buf)?,
+                on_ground: buf.read_u8()? != 0,
+            },
+            (ConnectionState::Play, 0x30) => OpenScreen {
+                window_id: buf.read_varint()?,
+                window_type: buf.read_varint()?,
+                window_title: buf.read_string()?,
+            },
+            (ConnectionState::Play, 0x39) => {
+                let count: i32 = buf.read_varint()?;
+                PlayerInfoRemove {
+                    players: (0..count)
+                        .map(|_| Ok(Uuid(buf.read_u128()?)))
+                        .collect::<Result<_>>()?,
+                }
+            }
+            (ConnectionState::Play, 0x3A) => {
+                let actions: u8 = buf.read_u8()?;
+                let count: i32 = buf.read_varint()?;
+                let players: Vec<(Uuid, Vec<PlayerInfoUpdateActions>)> = (0..count)
+                    .map(|_| {
+                        let uuid: Uuid = Uuid(buf.read_u128()?);
+                        Ok((uuid, read_player_info_actions(&mut buf, actions)?))
+                    })
+                    .collect::<Result<_>>()?;
+                PlayerInfoUpdate { actions, players }
+            }
+            (ConnectionState::Play, 0x3C) => SynchronizePlayerPosition {
+                x: buf.read_f64()?,
+                y: buf.read_f64()?,
+                z: buf.read_f64()?,
+                yaw: buf.read_f32()?,
+                pitch: buf.read_f32()?,
+                flags: buf.read_i8()?,
+                teleport_id: buf.read_varint()?,
+            },
+            (ConnectionState::Play, 0x3E) => {
+                let count: i32 = buf.read_varint()?;
+                RemoveEntities {
+                    entity_ids: (0..count)
+                        .map(|_| buf.read_varint())
+                        .collect::<Result<_>>()?,
+                }
+            }
+            (ConnectionState::Play, 0x41) => Respawn(Box::new(RespawnData {
+                dimension_type: buf.read_string()?,
+                dimension_name: buf.read_string()?,
+                hashed_seed: buf.read_i64()?,
+                gamemode: read_gamemode(&mut buf)?,
+                previous_gamemode: read_previous_gamemode(&mut buf)?,
+                is_debug: buf.read_u8()? != 0,
+                is_flat: buf.read_u8()? != 0,
+                data_kept: buf.read_u8()?,
+                death_location: read_optional(&mut buf, read_global_position)?,
+                portal_cooldown: buf.read_varint()?,
+            })),
+            (ConnectionState::Play, 0x42) => SetHeadRotation {
+                entity_id: buf.read_varint()?,
+                head_yaw: read_angle(&mut buf)?,
+            },
+            (ConnectionState::Play, 0x4E) => SetCenterChunk {
+                chunk_x: buf.read_varint()?,
+                chunk_z: buf.read_varint()?,
+            },
+            (ConnectionState::Play, 0x50) => SetDefaultSpawnPosition {
+                location: Position::from_i64(buf.read_i64()?),
+                angle: buf.read_f32()?,
+            },
+            (ConnectionState::Play, 0x52) => {
+                let entity_id: i32 = buf.read_varint()?;
+                let mut fields: Vec<(u8, EntityMetadataField)> = vec![];
+                loop {
+                    let index: u8 = buf.read_u8()?;
+                    if index == 0xFF {
+                        break;
+                    }
+                    fields.push((index, read_metadata_field(&mut buf)?));
+                }
+                SetEntityMetadata {
+                    entity_id,
+                    metadata: EntityMetadata(fields),
+                }
+            }
+            (ConnectionState::Play, 0x55) => {
+                let entity_id: i32 = buf.read_varint()?;
+                let mut equipment: Vec<(u8, Slot)> = vec![];
+                while !buf.is_empty() {
+                    let slot: u8 = buf.read_u8()?;
+                    equipment.push((slot, read_slot(&mut buf)?));
+                }
+                SetEquipment {
+                    entity_id,
+                    equipment,
+                }
+            }
+            (ConnectionState::Play, 0x64) => SystemChatMessage {
+                content: buf.read_string()?,
+                overlay: buf.read_u8()? != 0,
+            },
+            (ConnectionState::Play, 0x65) => SetTabListHeaderAndFooter {
+                header: buf.read_string()?,
+                footer: buf.read_string()?,
+            },
+            (ConnectionState::Play, 0x6A) => {
+                let entity_id: i32 = buf.read_varint()?;
+                let count: i32 = buf.read_varint()?;
+                let properties: Vec<AttributeProperty> = (0..count)
+                    .map(|_| {
+                        let key: String = buf.read_string()?;
+                        let value: f64 = buf.read_f64()?;
+                        let count: i32 = buf.read_varint()?;
+                        let modifiers: Vec<(Uuid, f64, u8)> = (0..count)
+                            .map(|_| Ok((Uuid(buf.read_u128()?), buf.read_f64()?, buf.read_u8()?)))
+                            .collect::<Result<_>>()?;
+                        Ok((key, value, modifiers))
+                    })
+                    .collect::<Result<_>>()?;
+                UpdateAttributes {
+                    entity_id,
+                    properties,
+                }
+            }
+            (ConnectionState::Play, 0x6C) => EntityEffect {
+                entity_id: buf.read_varint()?,
+                effect_id: buf.read_varint()?,
+                amplifier: buf.read_u8()?,
+                duration: buf.read_varint()?,
+                flags: buf.read_u8()?,
+                factor_codec: read_optional(&mut buf, |buf| Ok(fastnbt::from_reader(buf)?))?,
+            },
+            (_, _) => return Err(eyre!("Unknown packet, state={state} id={id:#04x}")),
+        };
+        ensure!(buf.is_empty(), "{packet} has {} bytes left over", buf.len());
+
+        Ok(packet)
+    }
+
+    /// Encodes a framed packet, without the length prefix.
+    /// A negative `compression_threshold` means that compression is disabled.
+    pub fn encode_frame(self, compression_threshold: i32) -> Result<Vec<u8>> {
+        use OutgoingPacket::*;
+        let (id, mut data) = match self {
+            packet @ (Disconnect
+            | EnableEncryption { .. }
+            | LegacyServerListPingResponse { .. }) => {
+                return Err(eyre!("{packet} is not sent as a frame"));
+            }
             StatusResponse { json_response } => (0x00, types::String(json_response).to_bytes()),
             DisconnectLogin { reason } => (0x00, types::String(reason).to_bytes()),
             BundleDelimiter => (0x00, vec![]),
             PingResponse { payload } => (0x01, payload.to_be_bytes().to_vec()),
+            SetCompression { threshold } => (0x03, VarInt(threshold).to_bytes()),
             SpawnEntity {
                 entity_id,
                 entity_uuid,
@@ -451,11 +992,46 @@ pub trait WritePacket: AsyncWrite + Unpin + Sized {
                 d.extend_from_slice(&velocity_z.to_be_bytes());
                 d
             }),
-            LoginSuccess { uuid, username } => (0x02, {
-                let mut d: Vec<u8> = Vec::with_capacity(16 + (16 + 1) + 1);
+            EncryptionRequest {
+                server_id,
+                public_key,
+                verify_token,
+            } => (0x01, {
+                let mut d: Vec<u8> = Vec::with_capacity(
+                    (5 + server_id.len()) + (5 + public_key.len()) + (5 + verify_token.len()),
+                );
+                d.write_string(&server_id);
+                d.write_varint(public_key.len() as i32);
+                d.extend_from_slice(&public_key);
+                d.write_varint(verify_token.len() as i32);
+                d.extend_from_slice(&verify_token);
+                d
+            }),
+            LoginSuccess {
+                uuid,
+                username,
+                properties,
+            } => (0x02, {
+                // The username and properties are encoded the same way as in Player Info Update
+                let profile: Vec<u8> = PlayerInfoUpdateActions::AddPlayer {
+                    name: username,
+                    properties,
+                }
+                .to_bytes();
+                let mut d: Vec<u8> = Vec::with_capacity(16 + profile.len());
                 d.extend_from_slice(&uuid.to_bytes());
-                d.write_string(&username);
-                d.push(0);
+                d.extend_from_slice(&profile);
+                d
+            }),
+            LoginPluginRequest {
+                message_id,
+                channel,
+                data,
+            } => (0x04, {
+                let mut d: Vec<u8> = Vec::with_capacity(5 + (5 + channel.len()) + data.len());
+                d.write_varint(message_id);
+                d.write_string(&channel);
+                d.extend_from_slice(&data);
                 d
             }),
             SpawnPlayer {
@@ -477,6 +1053,13 @@ pub trait WritePacket: AsyncWrite + Unpin + Sized {
                 d.push(Angle::from_deg(pitch).to_angle());
                 d
             }),
+            AcknowledgeBlockChange { sequence_id } => (0x06, VarInt(sequence_id).to_bytes()),
+            BlockUpdate { location, block_id } => (0x0A, {
+                let mut d: Vec<u8> = Vec::with_capacity(8 + 5);
+                d.extend_from_slice(&location.to_bytes());
+                d.write_varint(block_id);
+                d
+            }),
             SetContainerContent {
                 window_id,
                 state_id,
@@ -522,6 +1105,7 @@ pub trait WritePacket: AsyncWrite + Unpin + Sized {
                 }
                 d
             }),
+            DisconnectPlay { reason } => (0x1A, types::String(reason).to_bytes()),
             DisguisedChatMessage {
                 message,
                 chat_type,
@@ -544,101 +1128,45 @@ pub trait WritePacket: AsyncWrite + Unpin + Sized {
                 }
                 d
             }),
+            UnloadChunk { chunk_x, chunk_z } => (0x1E, {
+                let mut d: Vec<u8> = Vec::with_capacity(4 + 4);
+                d.extend_from_slice(&chunk_x.to_be_bytes());
+                d.extend_from_slice(&chunk_z.to_be_bytes());
+                d
+            }),
             KeepAlive { keep_alive_id } => (0x23, keep_alive_id.to_be_bytes().to_vec()),
             ChunkDataAndUpdateLight {
                 chunk_x,
                 chunk_z,
-                heightmaps,
                 data,
-                block_entities,
-                sky_light_mask,
-                block_light_mask,
-                empty_sky_light_mask,
-                empty_block_light_mask,
-                sky_light_arrays,
-                block_light_arrays,
             } => (0x24, {
-                let heightmaps: Vec<u8> = fastnbt::to_bytes(&heightmaps)?;
-                let data: Vec<u8> = data.to_bytes();
-                let block_entities: Vec<u8> = {
-                    let mut d: Vec<Vec<u8>> = Vec::with_capacity(block_entities.len());
-                    for (xz, y, t, data) in block_entities {
-                        let data: Vec<u8> = fastnbt::to_bytes(&data)?;
-                        let mut d_: Vec<u8> = Vec::with_capacity(1 + 2 + 5 + data.len());
-                        d_.push(xz);
-                        d_.extend_from_slice(&y.to_be_bytes());
-                        d_.write_varint(t);
-                        d_.extend_from_slice(&data);
-                        d.push(d_);
-                    }
-                    d.concat()
-                };
-
-                let mut d: Vec<u8> = Vec::with_capacity(
-                    4 + 4
-                        + heightmaps.len()
-                        + (5 + data.len())
-                        + (5 + block_entities.len())
-                        + (5 + sky_light_mask.len() * 8)
-                        + (5 + block_light_mask.len() * 8)
-                        + (5 + empty_sky_light_mask.len() * 8)
-                        + (5 + empty_block_light_mask.len() * 8)
-                        + (5 + sky_light_arrays.len() * 1024)
-                        + (5 + 1024),
-                );
+                let mut d: Vec<u8> = Vec::with_capacity(4 + 4 + data.len());
                 d.extend_from_slice(&chunk_x.to_be_bytes());
                 d.extend_from_slice(&chunk_z.to_be_bytes());
-                d.extend_from_slice(&heightmaps);
-                d.write_varint(data.len() as i32);
                 d.extend_from_slice(&data);
-                d.write_varint(block_entities.len() as i32);
-                d.extend_from_slice(&block_entities);
-                d.write_varint(sky_light_mask.len() as i32);
-                for i in sky_light_mask {
-                    d.extend_from_slice(&i.to_be_bytes());
-                }
-                d.write_varint(block_light_mask.len() as i32);
-                for i in block_light_mask {
-                    d.extend_from_slice(&i.to_be_bytes());
-                }
-                d.write_varint(empty_sky_light_mask.len() as i32);
-                for i in empty_sky_light_mask {
-                    d.extend_from_slice(&i.to_be_bytes());
-                }
-                d.write_varint(empty_block_light_mask.len() as i32);
-                for i in empty_block_light_mask {
-                    d.extend_from_slice(&i.to_be_bytes());
-                }
-                d.write_varint(sky_light_arrays.len() as i32);
-                for i in sky_light_arrays {
-                    d.extend_from_slice(&i);
-                }
-                d.write_varint(block_light_arrays.len() as i32);
-                for i in block_light_arrays {
-                    d.extend_from_slice(&i);
-                }
                 d
             }),
-            LoginPlay {
-                entity_id,
-                is_hardcore,
-                gamemode,
-                previous_gamemode,
-                dimension_names,
-                registry_codec,
-                dimension_type,
-                dimension_name,
-                hashed_seed,
-                max_players,
-                view_distance,
-                simulation_distance,
-                reduced_debug_info,
-                enable_respawn_screen,
-                is_debug,
-                is_flat,
-                death_location,
-                portal_cooldown,
-            } => (0x28, {
+            LoginPlay(data) => (0x28, {
+                let LoginPlayData {
+                    entity_id,
+                    is_hardcore,
+                    gamemode,
+                    previous_gamemode,
+                    dimension_names,
+                    registry_codec,
+                    dimension_type,
+                    dimension_name,
+                    hashed_seed,
+                    max_players,
+                    view_distance,
+                    simulation_distance,
+                    reduced_debug_info,
+                    enable_respawn_screen,
+                    is_debug,
+                    is_flat,
+                    death_location,
+                    portal_cooldown,
+                } = *data;
                 let dimension_names: Vec<u8> = {
                     let mut d: Vec<u8> = Vec::with_capacity(5);
                     d.write_varint(dimension_names.len() as i32);
@@ -781,6 +1309,52 @@ pub trait WritePacket: AsyncWrite + Unpin + Sized {
                 }
                 d
             }),
+            Respawn(data) => (0x41, {
+                let RespawnData {
+                    dimension_type,
+                    dimension_name,
+                    hashed_seed,
+                    gamemode,
+                    previous_gamemode,
+                    is_debug,
+                    is_flat,
+                    data_kept,
+                    death_location,
+                    portal_cooldown,
+                } = *data;
+                let mut d: Vec<u8> = Vec::with_capacity(
+                    (5 + dimension_type.len())
+                        + (5 + dimension_name.len())
+                        + 8
+                        + 1
+                        + 1
+                        + 1
+                        + 1
+                        + 1
+                        + 1
+                        + (death_location
+                            .as_ref()
+                            .map_or(0, |(ddn, _)| (5 + ddn.len()) + 8))
+                        + 5,
+                );
+                d.write_string(&dimension_type);
+                d.write_string(&dimension_name);
+                d.extend_from_slice(&hashed_seed.to_be_bytes());
+                d.push(gamemode as u8);
+                d.push(previous_gamemode.map_or(255, |gm: Gamemode| gm as u8));
+                d.extend_from_slice(&[
+                    is_debug as u8,
+                    is_flat as u8,
+                    data_kept,
+                    death_location.is_some() as u8,
+                ]);
+                if let Some((ddn, dl)) = death_location {
+                    d.write_string(&ddn);
+                    d.extend_from_slice(&dl.to_bytes());
+                }
+                d.write_varint(portal_cooldown);
+                d
+            }),
             SetHeadRotation {
                 entity_id,
                 head_yaw,
@@ -790,6 +1364,14 @@ pub trait WritePacket: AsyncWrite + Unpin + Sized {
                 d.push(Angle::from_deg(head_yaw).to_angle());
                 d
             }),
+            PlayerInfoRemove { players } => (0x39, {
+                let mut d: Vec<u8> = Vec::with_capacity(5 + players.len() * 16);
+                d.write_varint(players.len() as i32);
+                for uuid in players {
+                    d.extend_from_slice(&uuid.to_bytes());
+                }
+                d
+            }),
             PlayerInfoUpdate { actions, players } => (0x3A, {
                 let players: Vec<u8> = {
                     let mut d: Vec<u8> = Vec::with_capacity(5);
@@ -857,6 +1439,12 @@ pub trait WritePacket: AsyncWrite + Unpin + Sized {
                 }
                 d
             }),
+            SystemChatMessage { content, overlay } => (0x64, {
+                let mut d: Vec<u8> = Vec::with_capacity((5 + content.len()) + 1);
+                d.write_string(&content);
+                d.push(overlay as u8);
+                d
+            }),
             SetTabListHeaderAndFooter { header, footer } => (0x65, {
                 let mut d: Vec<u8> = Vec::with_capacity((5 + header.len()) + (5 + footer.len()));
                 d.write_string(&header);
@@ -928,8 +1516,58 @@ pub trait WritePacket: AsyncWrite + Unpin + Sized {
         // };
         let bytes_written: usize = data.write_varint(id);
         data.rotate_right(bytes_written);
-        prefix_with_length(&mut data);
-        self.write_all(&data).await?;
+        if compression_threshold >= 0 {
+            data = compress(data, compression_threshold)?;
+        }
+
+        Ok(data)
+    }
+}
+pub trait WritePacket: AsyncWrite + Unpin + Sized {
+    /// Writes a single packet.
+    /// A negative `compression_threshold` means that compression is disabled.
+    async fn write_packet(
+        &mut self,
+        packet: OutgoingPacket,
+        compression_threshold: i32,
+    ) -> Result<()> {
+        use OutgoingPacket::*;
+        match packet {
+            Disconnect | EnableEncryption { .. } => Ok(()),
+            LegacyServerListPingResponse {
+                protocol_version,
+                server_version,
+                motd,
+                online,
+                max,
+            } => {
+                // A kick packet with the fields separated by null characters, in UTF-16BE
+                let reason: Vec<u16> =
+                    format!("§1\0{protocol_version}\0{server_version}\0{motd}\0{online}\0{max}")
+                        .encode_utf16()
+                        .collect();
+                let mut d: Vec<u8> = Vec::with_capacity(1 + 2 + reason.len() * 2);
+                d.push(0xFF);
+                d.extend_from_slice(&(reason.len() as u16).to_be_bytes());
+                for c in reason {
+                    d.extend_from_slice(&c.to_be_bytes());
+                }
+                self.write_all(&d).await?;
+                self.flush().await?;
+                Ok(())
+            }
+            packet => {
+                self.write_frame(packet.encode_frame(compression_threshold)?)
+                    .await
+            }
+        }
+    }
+
+    /// Writes a frame encoded by [`OutgoingPacket::encode_frame`].
+    async fn write_frame(&mut self, mut frame: Vec<u8>) -> Result<()> {
+        prefix_with_length(&mut frame);
+        self.write_all(&frame).await?;
+        self.flush().await?;
 
         Ok(())
     }
@@ -969,3 +1607,222 @@ pub fn prefix_with_length(packet: &mut Vec<u8>) {
     let len_bytes: usize = packet.write_varint(packet.len() as i32);
     packet.rotate_right(len_bytes);
 }
+
+fn read_byte_array(buf: &mut VecDeque<u8>) -> Result<Vec<u8>> {
+    let len: usize = buf.read_varint()? as usize;
+    ensure!(len <= buf.len(), ReadError::EndOfFile);
+    Ok(buf.drain(..len).collect())
+}
+
+/// Encodes the heightmaps, blocks and light of Chunk Data and Update Light, without block entities.
+pub fn encode_chunk_data(chunk: &Chunk) -> Result<Vec<u8>> {
+    let heightmaps: Vec<u8> = fastnbt::to_bytes(&chunk.heightmaps.to_nbt(chunk))?;
+    let data: Vec<u8> = chunk.to_bytes();
+    let LightData {
+        sky_light_mask,
+        block_light_mask,
+        empty_sky_light_mask,
+        empty_block_light_mask,
+        sky_light_arrays,
+        block_light_arrays,
+    } = LightData::new(chunk);
+
+    let mut d: Vec<u8> = Vec::with_capacity(
+        heightmaps.len()
+            + (5 + data.len())
+            + 1
+            + (5 + sky_light_mask.len() * 8)
+            + (5 + block_light_mask.len() * 8)
+            + (5 + empty_sky_light_mask.len() * 8)
+            + (5 + empty_block_light_mask.len() * 8)
+            + (5 + sky_light_arrays.len() * (2 + 2048))
+            + (5 + block_light_arrays.len() * (2 + 2048)),
+    );
+    d.extend_from_slice(&heightmaps);
+    d.write_varint(data.len() as i32);
+    d.extend_from_slice(&data);
+    // No block entities
+    d.write_varint(0);
+    for mask in [
+        sky_light_mask,
+        block_light_mask,
+        empty_sky_light_mask,
+        empty_block_light_mask,
+    ] {
+        d.write_varint(mask.len() as i32);
+        for long in mask {
+            d.extend_from_slice(&long.to_be_bytes());
+        }
+    }
+    for arrays in [sky_light_arrays, block_light_arrays] {
+        d.write_varint(arrays.len() as i32);
+        for array in arrays {
+            d.write_varint(array.len() as i32);
+            d.extend_from_slice(&array);
+        }
+    }
+    Ok(d)
+}
+
+/// Reads an item stack, whose NBT is an empty compound if it has none.
+fn read_slot(buf: &mut VecDeque<u8>) -> Result<Slot> {
+    if buf.read_u8()? == 0 {
+        return Ok(None);
+    }
+    let item_id: i32 = buf.read_varint()?;
+    let count: i8 = buf.read_i8()?;
+    let nbt: Value = if buf.front() == Some(&0) {
+        buf.pop_front();
+        Value::Compound(Default::default())
+    } else {
+        fastnbt::from_reader(&mut *buf)?
+    };
+    Ok(Some((item_id, count, nbt)))
+}
+
+fn read_optional<T>(
+    buf: &mut VecDeque<u8>,
+    read: impl FnOnce(&mut VecDeque<u8>) -> Result<T>,
+) -> Result<Option<T>> {
+    if buf.read_u8()? == 0 {
+        return Ok(None);
+    }
+    read(buf).map(Some)
+}
+
+fn read_angle(buf: &mut VecDeque<u8>) -> Result<f32> {
+    Ok(Angle::from_angle(buf.read_u8()?).get_deg())
+}
+
+fn read_gamemode(buf: &mut VecDeque<u8>) -> Result<Gamemode> {
+    let gamemode: u8 = buf.read_u8()?;
+    Ok(match gamemode {
+        0 => Gamemode::Survival,
+        1 => Gamemode::Creative,
+        2 => Gamemode::Adventure,
+        3 => Gamemode::Spectator,
+        _ => return Err(eyre!("Invalid gamemode {gamemode}")),
+    })
+}
+
+/// No previous gamemode is sent as 255.
+fn read_previous_gamemode(buf: &mut VecDeque<u8>) -> Result<Option<Gamemode>> {
+    if buf.front() == Some(&255) {
+        buf.pop_front();
+        return Ok(None);
+    }
+    read_gamemode(buf).map(Some)
+}
+
+/// A dimension name and a position in it.
+fn read_global_position(buf: &mut VecDeque<u8>) -> Result<(String, Position)> {
+    Ok((buf.read_string()?, Position::from_i64(buf.read_i64()?)))
+}
+
+/// Reads the actions of a player in Player Info Update, in the order of their bits.
+fn read_player_info_actions(
+    buf: &mut VecDeque<u8>,
+    actions: u8,
+) -> Result<Vec<PlayerInfoUpdateActions>> {
+    use PlayerInfoUpdateActions::*;
+    let mut d: Vec<PlayerInfoUpdateActions> = vec![];
+    if actions & 0x01 != 0 {
+        let name: String = buf.read_string()?;
+        let count: i32 = buf.read_varint()?;
+        let properties: Vec<Property> = (0..count)
+            .map(|_| {
+                Ok((
+                    buf.read_string()?,
+                    buf.read_string()?,
+                    read_optional(buf, |buf| buf.read_string())?,
+                ))
+            })
+            .collect::<Result<_>>()?;
+        d.push(AddPlayer { name, properties });
+    }
+    if actions & 0x02 != 0 {
+        d.push(InitializeChat {
+            signature: read_optional(buf, |buf| {
+                Ok((
+                    Uuid(buf.read_u128()?),
+                    buf.read_i64()?,
+                    read_byte_array(buf)?,
+                    read_byte_array(buf)?,
+                ))
+            })?,
+        });
+    }
+    if actions & 0x04 != 0 {
+        d.push(UpdateGamemode {
+            gamemode: buf.read_varint()?,
+        });
+    }
+    if actions & 0x08 != 0 {
+        d.push(UpdateListed {
+            listed: buf.read_u8()? != 0,
+        });
+    }
+    if actions & 0x10 != 0 {
+        d.push(UpdateLatency {
+            ping: buf.read_varint()?,
+        });
+    }
+    if actions & 0x20 != 0 {
+        d.push(UpdateDisplayName {
+            display_name: read_optional(buf, |buf| buf.read_string())?,
+        });
+    }
+    Ok(d)
+}
+
+/// Reads the types of entity metadata that the server sends.
+fn read_metadata_field(buf: &mut VecDeque<u8>) -> Result<EntityMetadataField> {
+    let field_type: i32 = buf.read_varint()?;
+    Ok(match field_type {
+        0 => EntityMetadataField::Byte(buf.read_u8()?),
+        1 => EntityMetadataField::VarInt(buf.read_varint()?),
+        2 => EntityMetadataField::VarLong(buf.read_varlong()?),
+        8 => EntityMetadataField::Boolean(buf.read_u8()? != 0),
+        _ => return Err(eyre!("Unsupported entity metadata type {field_type}")),
+    })
+}
+
+/// Converts packet ID and data into the compressed format, without the length prefix.
+pub fn compress(packet: Vec<u8>, threshold: i32) -> Result<Vec<u8>> {
+    let mut d: Vec<u8> = Vec::with_capacity(5 + packet.len());
+    if packet.len() < threshold as usize {
+        // Below the threshold, the data length is 0 and the packet is sent as is
+        d.write_varint(0);
+        d.extend_from_slice(&packet);
+    } else {
+        d.write_varint(packet.len() as i32);
+        let mut encoder: ZlibEncoder<Vec<u8>> = ZlibEncoder::new(d, Compression::default());
+        encoder.write_all(&packet)?;
+        d = encoder.finish()?;
+    }
+    Ok(d)
+}
+
+/// Converts a compressed packet, without the length prefix, back into packet ID and data.
+pub fn decompress(mut packet: VecDeque<u8>, threshold: i32) -> Result<VecDeque<u8>> {
+    let data_length: i32 = packet.read_varint()?;
+    if data_length == 0 {
+        return Ok(packet);
+    }
+    ensure!(
+        (threshold..=MAX_DATA_LENGTH).contains(&data_length),
+        "Badly compressed packet: size of {data_length} is outside the allowed range"
+    );
+
+    let mut d: Vec<u8> = Vec::with_capacity(data_length as usize);
+    ZlibDecoder::new(packet.make_contiguous() as &[u8])
+        .take(data_length as u64 + 1)
+        .read_to_end(&mut d)?;
+    if d.len() != data_length as usize {
+        return Err(eyre!(
+            "Badly compressed packet: expected {data_length} bytes, got {}",
+            d.len()
+        ));
+    }
+    Ok(VecDeque::from(d))
+}
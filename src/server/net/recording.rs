@@ -0,0 +1,125 @@
+use std::{io::ErrorKind, path::Path, time::Duration};
+
+use eyre::{bail, ensure, Context, Result};
+use log::warn;
+use strum_macros::Display;
+use tokio::{
+    fs::File,
+    io::{AsyncRead, AsyncReadExt, AsyncWriteExt, BufWriter},
+    sync::mpsc::{unbounded_channel, UnboundedSender},
+    time::Instant,
+};
+
+use crate::PROTOCOL_VERSION;
+
+use super::packets::{prefix_with_length, ReadPacket};
+
+/// The start of every recording, followed by the protocol version.
+pub const RECORDING_MAGIC: &[u8; 8] = b"MCRECORD";
+
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Serverbound,
+    Clientbound,
+}
+
+/// A frame as read from or written to a connection, after decryption and before decompression.
+#[derive(Debug, PartialEq)]
+pub struct Record {
+    /// Since the connection was accepted.
+    pub time: Duration,
+    pub direction: Direction,
+    pub frame: Vec<u8>,
+}
+impl Record {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut frame: Vec<u8> = self.frame.clone();
+        prefix_with_length(&mut frame);
+
+        let mut d: Vec<u8> = Vec::with_capacity(8 + 1 + frame.len());
+        d.extend_from_slice(&(self.time.as_micros() as u64).to_be_bytes());
+        d.push(match self.direction {
+            Direction::Serverbound => 0,
+            Direction::Clientbound => 1,
+        });
+        d.extend_from_slice(&frame);
+        d
+    }
+
+    /// Returns `None` at the end of the recording.
+    pub async fn read<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<Self>> {
+        let time: u64 = match reader.read_u64().await {
+            Ok(time) => time,
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let direction: Direction = match reader.read_u8().await? {
+            0 => Direction::Serverbound,
+            1 => Direction::Clientbound,
+            direction => bail!("Invalid direction {direction}"),
+        };
+
+        Ok(Some(Self {
+            time: Duration::from_micros(time),
+            direction,
+            frame: reader.read_frame().await?,
+        }))
+    }
+}
+
+/// Reads the start of a recording and returns its protocol version.
+pub async fn read_recording_header<R: AsyncRead + Unpin>(reader: &mut R) -> Result<u16> {
+    let mut magic: [u8; 8] = [0; 8];
+    reader.read_exact(&mut magic).await?;
+    ensure!(&magic == RECORDING_MAGIC, "Not a recording");
+    Ok(reader.read_u16().await?)
+}
+
+/// Records the frames of a single connection to a file.
+/// Both halves of the connection share a clone.
+#[derive(Clone)]
+pub struct Recorder {
+    start: Instant,
+    record_sender: UnboundedSender<Record>,
+}
+impl Recorder {
+    pub async fn create(path: &Path) -> Result<Self> {
+        let mut file: BufWriter<File> = BufWriter::new(
+            File::create(path)
+                .await
+                .wrap_err_with(|| format!("Could not create {}", path.display()))?,
+        );
+        file.write_all(RECORDING_MAGIC).await?;
+        file.write_u16(PROTOCOL_VERSION).await?;
+
+        // Records are written until both halves of the connection are gone
+        let (record_sender, mut record_receiver) = unbounded_channel::<Record>();
+        let path: String = path.display().to_string();
+        tokio::spawn(async move {
+            while let Some(record) = record_receiver.recv().await {
+                let mut result: std::io::Result<()> = file.write_all(&record.to_bytes()).await;
+                // Flushed whenever there is nothing else to write, so a crash loses little
+                if result.is_ok() && record_receiver.is_empty() {
+                    result = file.flush().await;
+                }
+                if let Err(e) = result {
+                    warn!("Could not write to {path}: {e}");
+                    return;
+                }
+            }
+        });
+
+        Ok(Self {
+            start: Instant::now(),
+            record_sender,
+        })
+    }
+
+    pub fn record(&self, direction: Direction, frame: &[u8]) {
+        let _ = self.record_sender.send(Record {
+            time: self.start.elapsed(),
+            direction,
+            frame: frame.to_vec(),
+        });
+    }
+}
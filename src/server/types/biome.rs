@@ -0,0 +1,52 @@
+use std::{fmt, str::FromStr};
+
+use eyre::{eyre, Report, Result};
+
+// `Biome` (plains by default), `from_id` and `BIOMES`
+include!(concat!(env!("OUT_DIR"), "/biome.rs"));
+
+/// Climate of a biome from `ref/1_20_1_biomes.json`.
+#[derive(Debug, PartialEq)]
+pub struct BiomeInfo {
+    pub name: &'static str,
+    pub temperature: f32,
+    pub downfall: f32,
+    pub has_precipitation: bool,
+}
+
+impl Biome {
+    /// The id of the biome in the registry codec sent to clients.
+    pub fn id(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn info(&self) -> &'static BiomeInfo {
+        &BIOMES[self.id() as usize]
+    }
+
+    /// The vanilla name of the biome, without the `minecraft:` namespace.
+    pub fn name(&self) -> &'static str {
+        self.info().name
+    }
+}
+
+impl fmt::Display for Biome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "minecraft:{}", self.name())
+    }
+}
+
+/// Parses the name of a biome, with or without the `minecraft:` namespace.
+impl FromStr for Biome {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let name: &str = s.trim();
+        let name: &str = name.strip_prefix("minecraft:").unwrap_or(name);
+        BIOMES
+            .iter()
+            .position(|b| b.name == name)
+            .and_then(|id| Self::from_id(id as u8))
+            .ok_or_else(|| eyre!("Unknown biome {s}"))
+    }
+}
@@ -0,0 +1,37 @@
+/// The face of a block that a player is digging or clicking.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BlockFace {
+    Bottom,
+    Top,
+    North,
+    South,
+    West,
+    East,
+}
+impl BlockFace {
+    pub fn from_id(id: i32) -> Option<Self> {
+        use BlockFace::*;
+        Some(match id {
+            0 => Bottom,
+            1 => Top,
+            2 => North,
+            3 => South,
+            4 => West,
+            5 => East,
+            _ => return None,
+        })
+    }
+
+    /// The direction of the block next to this face.
+    pub fn offset(&self) -> (i32, i32, i32) {
+        use BlockFace::*;
+        match self {
+            Bottom => (0, -1, 0),
+            Top => (0, 1, 0),
+            North => (0, 0, -1),
+            South => (0, 0, 1),
+            West => (-1, 0, 0),
+            East => (1, 0, 0),
+        }
+    }
+}
@@ -1,12 +1,24 @@
-use super::{Block, ChunkSection, Dimension};
+use super::{Biome, Block, ChunkSection, Dimension, Heightmaps};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Chunk {
     pub dimension: Dimension,
     pub chunk_sections: Vec<ChunkSection>,
+    /// Kept up to date by `set_block`.
+    pub heightmaps: Heightmaps,
 }
 impl Chunk {
-    pub fn get_block(&self, x: u8, mut y: i32, z: u8) -> Option<&Block> {
+    pub fn new(dimension: Dimension, chunk_sections: Vec<ChunkSection>) -> Self {
+        let mut chunk: Self = Self {
+            dimension,
+            chunk_sections,
+            heightmaps: Heightmaps::default(),
+        };
+        chunk.heightmaps = Heightmaps::new(&chunk);
+        chunk
+    }
+
+    pub fn get_block(&self, x: u8, mut y: i32, z: u8) -> Option<Block> {
         if self.dimension == Dimension::Overworld {
             y += 64;
         }
@@ -16,20 +28,55 @@ impl Chunk {
             .map(|cs| cs.get_block(x, (y % 16) as u8, z))?
     }
 
+    /// Replaces a block and updates the heightmaps. Returns `false` if the block is out of bounds.
+    pub fn set_block(&mut self, x: u8, y: i32, z: u8, block: Block) -> bool {
+        let section_y: i32 = (y - self.dimension.min_height()).div_euclid(16);
+        let Some(chunk_section) = usize::try_from(section_y)
+            .ok()
+            .and_then(|i| self.chunk_sections.get_mut(i))
+        else {
+            return false;
+        };
+        chunk_section.set_block(x, y.rem_euclid(16) as u8, z, block);
+
+        let mut heightmaps: Heightmaps = std::mem::take(&mut self.heightmaps);
+        heightmaps.update(self, x, y, z);
+        self.heightmaps = heightmaps;
+        true
+    }
+
     pub fn is_empty(&self, x: u8, y: i32, z: u8) -> bool {
-        self.get_block(x, y, z).unwrap_or(&Block::Air).is_empty()
+        self.get_block(x, y, z).unwrap_or(Block::Air).is_empty()
     }
 
     pub fn max_height_at(&self, x: u8, z: u8) -> Option<i32> {
+        self.max_height_where(x, z, |b| !b.is_empty())
+    }
+
+    /// The height of the highest block matching the predicate.
+    pub fn max_height_where(
+        &self,
+        x: u8,
+        z: u8,
+        predicate: impl Fn(&Block) -> bool,
+    ) -> Option<i32> {
         for i in (0..self.chunk_sections.len()).rev() {
-            if let Some(y) = self.chunk_sections[i].max_height_at(x, z) {
+            if let Some(y) = self.chunk_sections[i].max_height_where(x, z, &predicate) {
                 return Some(i as i32 * 16 + y as i32 + self.dimension.min_height());
             }
         }
         None
     }
 
-    pub fn highest_block_at(&self, x: u8, z: u8) -> Option<&Block> {
+    pub fn get_biome(&self, x: u8, mut y: i32, z: u8) -> Option<&Biome> {
+        y -= self.dimension.min_height();
+
+        self.chunk_sections
+            .get(y as usize / 16)
+            .map(|cs| cs.get_biome(x, (y % 16) as u8, z))?
+    }
+
+    pub fn highest_block_at(&self, x: u8, z: u8) -> Option<Block> {
         for i in (0..self.chunk_sections.len()).rev() {
             if let Some(block) = self.chunk_sections[i].get_highest_block_at(x, z) {
                 return Some(block);
@@ -46,7 +93,7 @@ impl Chunk {
                 &self
                     .chunk_sections
                     .get(i)
-                    .unwrap_or(&ChunkSection { blocks: vec![] })
+                    .unwrap_or(&ChunkSection::new(vec![], Biome::default()))
                     .to_bytes(),
             );
         }
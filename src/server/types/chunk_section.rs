@@ -1,25 +1,51 @@
-use super::{leb128::WriteVarInt, Block};
+use super::{leb128::WriteVarInt, Biome, Block, PalettedContainer};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ChunkSection {
-    //? Maybe place size restriction: [Block; 4096]
-    pub blocks: Vec<Block>,
-    // TODO: pub biomes: Vec<Biome>,
+    pub blocks: PalettedContainer,
+    /// One biome for every 4x4x4 blocks, in the same order as the blocks.
+    pub biomes: Vec<Biome>,
+    /// Light levels packed two to a byte, in the same order as the blocks and low nibble first.
+    pub sky_light: Vec<u8>,
+    pub block_light: Vec<u8>,
 }
 impl ChunkSection {
-    pub fn get_block(&self, x: u8, y: u8, z: u8) -> Option<&Block> {
-        self.blocks
-            .get((x as usize & 0xF) + (z as usize & 0xF) * 16 + (y as usize & 0xF) * 256)
+    /// A dark section of a single biome. Missing blocks are air.
+    pub fn new(blocks: Vec<Block>, biome: Biome) -> Self {
+        Self {
+            blocks: PalettedContainer::from_blocks(&blocks),
+            biomes: vec![biome; 64],
+            sky_light: vec![0; 2048],
+            block_light: vec![0; 2048],
+        }
+    }
+
+    pub fn get_block(&self, x: u8, y: u8, z: u8) -> Option<Block> {
+        Block::from_state_id(self.get_state_id(x, y, z))
+    }
+
+    pub fn get_state_id(&self, x: u8, y: u8, z: u8) -> u16 {
+        self.blocks.get(index(x, y, z))
     }
 
     pub fn is_empty(&self, x: u8, y: u8, z: u8) -> bool {
-        self.get_block(x, y, z).unwrap_or(&Block::Air).is_empty()
+        self.get_block(x, y, z).unwrap_or(Block::Air).is_empty()
+    }
+
+    /// Replaces a block and returns the old one.
+    pub fn set_block(&mut self, x: u8, y: u8, z: u8, block: Block) -> Block {
+        self.blocks.set(index(x, y, z), block)
     }
 
     pub fn max_height_at(&self, x: u8, z: u8) -> Option<u8> {
+        self.max_height_where(x, z, |b| !b.is_empty())
+    }
+
+    /// The height of the highest block matching the predicate.
+    pub fn max_height_where(&self, x: u8, z: u8, predicate: impl Fn(&Block) -> bool) -> Option<u8> {
         for y in (0..16).rev() {
             if let Some(block) = self.get_block(x, y, z) {
-                if !block.is_empty() {
+                if predicate(&block) {
                     return Some(y);
                 }
             }
@@ -27,7 +53,13 @@ impl ChunkSection {
         None
     }
 
-    pub fn get_highest_block_at(&self, x: u8, z: u8) -> Option<&Block> {
+    pub fn get_biome(&self, x: u8, y: u8, z: u8) -> Option<&Biome> {
+        self.biomes.get(
+            (x as usize >> 2 & 0x3) + (z as usize >> 2 & 0x3) * 4 + (y as usize >> 2 & 0x3) * 16,
+        )
+    }
+
+    pub fn get_highest_block_at(&self, x: u8, z: u8) -> Option<Block> {
         for y in (0..16).rev() {
             if let Some(block) = self.get_block(x, y, z) {
                 return Some(block);
@@ -37,123 +69,69 @@ impl ChunkSection {
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
-        const AIR_STATE_ID: u16 = 0;
-        const VOID_AIR_STATE_ID: u16 = 12817;
-        const CAVE_AIR_STATE_ID: u16 = 12818;
-
-        let mut block_count: u16 = 0;
-        let mut blocks: Vec<u16> = self
-            .blocks
-            .iter()
-            .take(4096)
-            .map(|b| {
-                let sid: u16 = b.get_state_id();
-                // TODO: check if this is faster than filtering non-air blocks
-                if sid != AIR_STATE_ID && sid != VOID_AIR_STATE_ID && sid != CAVE_AIR_STATE_ID {
-                    block_count += 1;
-                }
-                sid
-            })
-            .collect();
-        blocks.resize(4096, AIR_STATE_ID);
-
-        // TODO: check if this is faster than counting when mapping and making a hashset
-        let mut unique_blocks: Vec<u16> = blocks.clone();
-        unique_blocks.sort_unstable();
-        unique_blocks.dedup();
-
-        let bpe: u8 = match unique_blocks.len() {
-            0..=1 => 0,
-            2..=15 => 4,
-            16..=31 => 5,
-            32..=63 => 6,
-            64..=127 => 7,
-            128..=255 => 8,
-            _ => 15,
-        };
+        let mut biomes: Vec<u8> = self.biomes.iter().take(64).map(|b| b.id()).collect();
+        biomes.resize(64, Biome::default().id());
 
-        let block_states: Vec<u8> = match bpe {
-            0 => {
-                let mut d: Vec<u8> = Vec::with_capacity(1 + 3 + 1);
-                d.push(0);
-                d.write_varint(blocks[0] as i32);
+        let mut unique_biomes: Vec<u8> = biomes.clone();
+        unique_biomes.sort_unstable();
+        unique_biomes.dedup();
+
+        let biomes: Vec<u8> = match unique_biomes.len() {
+            0..=1 => {
+                let mut d: Vec<u8> = Vec::with_capacity(1 + 1 + 1);
                 d.push(0);
+                d.write_varint(biomes[0] as i32);
+                d.write_varint(0);
                 d
             }
-            4..=8 => {
-                let palette: Vec<u8> = {
-                    let mut d: Vec<u8> = Vec::with_capacity(3 + unique_blocks.len() * 3);
-                    for block in &unique_blocks {
-                        d.write_varint(*block as i32);
-                    }
-                    d
+            length => {
+                // Palettes of biomes hold up to 8 entries, otherwise ids are used directly
+                let bpe: u8 = match length {
+                    2 => 1,
+                    3..=4 => 2,
+                    5..=8 => 3,
+                    _ => 6,
                 };
+                let biomes_per_long: usize = (i64::BITS / bpe as u32) as usize;
+                let data_length: usize = 64_usize.div_ceil(biomes_per_long);
 
-                let blocks_per_long: usize = (i64::BITS / bpe as u32) as usize;
-                let data_length: usize = 4096 / blocks_per_long;
-
-                let data: Vec<u8> = {
-                    let mut d: Vec<i64> = vec![0; data_length];
-                    for i in 0..4096 {
-                        d[i / blocks_per_long] |= (unique_blocks
+                let mut data: Vec<i64> = vec![0; data_length];
+                for (i, &biome) in biomes.iter().enumerate() {
+                    let value: i64 = if bpe <= 3 {
+                        unique_biomes
                             .iter()
-                            .position(|&b| b == blocks[i])
-                            .unwrap_or_default()
-                            as i64)
-                            << (bpe as usize * (i % blocks_per_long));
-                    }
-
-                    d.into_iter()
-                        .map(|l: i64| l.to_be_bytes())
-                        .collect::<Vec<[u8; 8]>>()
-                        .concat()
-                };
+                            .position(|&b| b == biome)
+                            .unwrap_or_default() as i64
+                    } else {
+                        biome as i64
+                    };
+                    data[i / biomes_per_long] |= value << (bpe as usize * (i % biomes_per_long));
+                }
 
-                let mut d: Vec<u8> = Vec::with_capacity(1 + 5 + palette.len() + 2 + data.len());
+                let mut d: Vec<u8> = Vec::with_capacity(1 + 1 + length + 1 + data_length * 8);
                 d.push(bpe);
-                d.write_varint(unique_blocks.len() as i32);
-                d.extend_from_slice(&palette);
-                d.write_varint(data_length as i32);
-                d.extend_from_slice(&data);
-                d
-            }
-            _ => {
-                let blocks_per_long: usize = (i64::BITS / bpe as u32) as usize;
-                let data_length: usize = 4096 / blocks_per_long;
-
-                let data: Vec<u8> = {
-                    let mut d: Vec<i64> = vec![0; data_length];
-                    for i in 0..4096 {
-                        d[i / blocks_per_long] |=
-                            (blocks[i] as i64) << (bpe * (i % blocks_per_long) as u8);
+                if bpe <= 3 {
+                    d.write_varint(length as i32);
+                    for &biome in &unique_biomes {
+                        d.write_varint(biome as i32);
                     }
-
-                    d.into_iter()
-                        .map(|l: i64| l.to_be_bytes())
-                        .collect::<Vec<[u8; 8]>>()
-                        .concat()
-                };
-
-                let mut d: Vec<u8> = Vec::with_capacity(1 + 2 + data.len());
-                d.push(bpe);
+                }
                 d.write_varint(data_length as i32);
-                d.extend_from_slice(&data);
+                for long in data {
+                    d.extend_from_slice(&long.to_be_bytes());
+                }
                 d
             }
         };
 
-        let biomes: Vec<u8> = {
-            let mut d: Vec<u8> = Vec::with_capacity(1 + 3 + 1);
-            d.push(0);
-            d.write_varint(56);
-            d.write_varint(0);
-            d
-        };
-
-        let mut d: Vec<u8> = Vec::with_capacity(2 + block_states.len() + biomes.len());
-        d.extend_from_slice(&block_count.to_be_bytes());
-        d.extend_from_slice(&block_states);
+        let mut d: Vec<u8> = Vec::with_capacity(2 + biomes.len());
+        d.extend_from_slice(&self.blocks.non_air_count().to_be_bytes());
+        self.blocks.write_to(&mut d);
         d.extend_from_slice(&biomes);
         d
     }
 }
+
+fn index(x: u8, y: u8, z: u8) -> usize {
+    (x as usize & 0xF) + (z as usize & 0xF) * 16 + (y as usize & 0xF) * 256
+}
@@ -0,0 +1,26 @@
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DiggingStatus {
+    Started,
+    Cancelled,
+    Finished,
+    DropItemStack,
+    DropItem,
+    /// Shooting arrows or finishing eating.
+    ReleaseUseItem,
+    SwapItemInHand,
+}
+impl DiggingStatus {
+    pub fn from_id(id: i32) -> Option<Self> {
+        use DiggingStatus::*;
+        Some(match id {
+            0 => Started,
+            1 => Cancelled,
+            2 => Finished,
+            3 => DropItemStack,
+            4 => DropItem,
+            5 => ReleaseUseItem,
+            6 => SwapItemInHand,
+            _ => return None,
+        })
+    }
+}
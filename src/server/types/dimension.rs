@@ -1,10 +1,23 @@
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+use serde_derive::{Deserialize, Serialize};
+
+/// The type of a dimension, which decides its height and how the client renders it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Dimension {
     Overworld,
     Nether,
     End,
 }
 impl Dimension {
+    /// The name of the dimension type in the registry codec.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Overworld => "minecraft:overworld",
+            Self::Nether => "minecraft:the_nether",
+            Self::End => "minecraft:the_end",
+        }
+    }
+
     pub fn chunk_section_count(&self) -> i32 {
         match self {
             Self::Overworld => 24,
@@ -1,4 +1,7 @@
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
 #[repr(u8)]
 pub enum Gamemode {
     Survival = 0,
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use fastnbt::{LongArray, Value};
+
+use super::{Block, Chunk};
+
+/// The heights above the highest blocks of every column of a chunk,
+/// counted from the bottom of the dimension, or 0 if a column is empty.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Heightmaps {
+    /// Blocks that stop movement and rain, or hold a fluid.
+    pub motion_blocking: Vec<u16>,
+    /// Every block but air.
+    pub world_surface: Vec<u16>,
+}
+impl Default for Heightmaps {
+    fn default() -> Self {
+        Self {
+            motion_blocking: vec![0; 256],
+            world_surface: vec![0; 256],
+        }
+    }
+}
+impl Heightmaps {
+    pub fn new(chunk: &Chunk) -> Self {
+        let mut heightmaps: Self = Self::default();
+        for x in 0..16 {
+            for z in 0..16 {
+                heightmaps.update_column(chunk, x, z);
+            }
+        }
+        heightmaps
+    }
+
+    /// Updates the heights after a block of the chunk changed.
+    pub fn update(&mut self, chunk: &Chunk, x: u8, y: i32, z: u8) {
+        let i: usize = index(x, z);
+        let height: u16 = (y - chunk.dimension.min_height() + 1) as u16;
+        let block: Block = chunk.get_block(x, y, z).unwrap_or(Block::Air);
+
+        // Only removing the highest block needs the column to be searched
+        if (height >= self.world_surface[i] && block.is_empty())
+            || (height >= self.motion_blocking[i] && !block.blocks_motion())
+        {
+            self.update_column(chunk, x, z);
+        }
+        if !block.is_empty() {
+            self.world_surface[i] = self.world_surface[i].max(height);
+        }
+        if block.blocks_motion() {
+            self.motion_blocking[i] = self.motion_blocking[i].max(height);
+        }
+    }
+
+    fn update_column(&mut self, chunk: &Chunk, x: u8, z: u8) {
+        let height = |y: Option<i32>| {
+            y.map(|y| (y - chunk.dimension.min_height() + 1) as u16)
+                .unwrap_or_default()
+        };
+        let i: usize = index(x, z);
+        self.world_surface[i] = height(chunk.max_height_at(x, z));
+        self.motion_blocking[i] = height(chunk.max_height_where(x, z, Block::blocks_motion));
+    }
+
+    /// The heightmaps sent in Chunk Data and saved in region files, packed like vanilla.
+    pub fn to_nbt(&self, chunk: &Chunk) -> Value {
+        let height: u32 = chunk.dimension.chunk_section_count() as u32 * 16;
+        Value::Compound(HashMap::from([
+            (
+                "MOTION_BLOCKING".to_string(),
+                Value::LongArray(pack(&self.motion_blocking, height)),
+            ),
+            (
+                "WORLD_SURFACE".to_string(),
+                Value::LongArray(pack(&self.world_surface, height)),
+            ),
+        ]))
+    }
+}
+
+fn index(x: u8, z: u8) -> usize {
+    (x as usize & 0xF) + (z as usize & 0xF) * 16
+}
+
+/// Packs heights of up to `height` into longs. Entries do not span longs.
+fn pack(heights: &[u16], height: u32) -> LongArray {
+    let bits: u32 = u32::BITS - height.leading_zeros();
+    let per_long: usize = (u64::BITS / bits) as usize;
+
+    let mut data: Vec<i64> = vec![0; heights.len().div_ceil(per_long)];
+    for (i, &height) in heights.iter().enumerate() {
+        data[i / per_long] |= (height as i64) << (bits as usize * (i % per_long));
+    }
+    LongArray::new(data)
+}
@@ -0,0 +1,44 @@
+use super::Block;
+
+// `ITEMS`
+include!(concat!(env!("OUT_DIR"), "/item.rs"));
+
+/// An item from `ref/1.20.1_items.json`.
+#[derive(Debug, PartialEq)]
+pub struct ItemInfo {
+    pub id: u16,
+    /// Missing for some items.
+    pub display_name: Option<&'static str>,
+    /// The state of the block placed by the item, if it places one.
+    pub block_state_id: Option<u16>,
+}
+
+impl ItemInfo {
+    pub fn from_id(id: i32) -> Option<&'static Self> {
+        usize::try_from(id).ok().and_then(|id| ITEMS.get(id))
+    }
+
+    /// The block placed by the item.
+    pub fn block(&self) -> Option<Block> {
+        self.block_state_id.and_then(Block::from_state_id)
+    }
+
+    /// How fast the item breaks the blocks it is best at, 1 for items that are not tools.
+    pub fn mining_speed(&self) -> f32 {
+        match self.display_name.and_then(|name| name.rsplit_once(' ')) {
+            Some((tier, "Pickaxe" | "Shovel" | "Axe" | "Hoe")) => match tier {
+                "Wooden" => 2.0,
+                "Stone" => 4.0,
+                "Iron" => 6.0,
+                "Diamond" => 8.0,
+                "Netherite" => 9.0,
+                "Golden" => 12.0,
+                _ => 1.0,
+            },
+            // Cobwebs
+            Some((_, "Sword")) => 15.0,
+            _ if self.display_name == Some("Shears") => 15.0,
+            _ => 1.0,
+        }
+    }
+}
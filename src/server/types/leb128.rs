@@ -155,6 +155,8 @@ pub use varlong::*;
 pub mod varlong {
     pub const MAX_BYTES: i64 = 10;
 
+    use std::collections::VecDeque;
+
     use eyre::{ensure, eyre, Result};
 
     use crate::server::util::ReadError;
@@ -173,6 +175,28 @@ pub mod varlong {
     pub trait ReadVarLong {
         fn read_varlong(&mut self) -> Result<i64>;
     }
+    impl ReadVarLong for VecDeque<u8> {
+        fn read_varlong(&mut self) -> Result<i64> {
+            let mut result: i64 = 0;
+            let mut shift: i64 = 0;
+
+            loop {
+                let byte: u8 = self.pop_front().ok_or(ReadError::EndOfFile)?;
+                let low_bits: i64 = (byte & !CONTINUATION_BIT) as i64;
+                result |= low_bits << shift;
+
+                if byte & CONTINUATION_BIT == 0 {
+                    return Ok(result);
+                }
+
+                shift += 7;
+
+                if shift == MAX_BYTES * 7 {
+                    return Err(eyre!(ReadError::Overflow));
+                }
+            }
+        }
+    }
     impl ReadVarLong for Vec<u8> {
         fn read_varlong(&mut self) -> Result<i64> {
             let mut result: i64 = 0;
@@ -2,16 +2,22 @@ use crate::server::util::import_all;
 
 import_all!(
     angle,
+    biome,
     block_states,
     block,
+    block_face,
     chunk_section,
     chunk,
+    digging_status,
     dimension,
     entity_metadata_field,
     entity_metadata,
     gamemode,
+    heightmaps,
     interaction_type,
+    item,
     leb128,
+    paletted_container,
     player_info_update_actions,
     position,
     string,
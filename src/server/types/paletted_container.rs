@@ -0,0 +1,269 @@
+use super::{leb128::WriteVarInt, Block};
+
+const BLOCKS_PER_SECTION: usize = 4096;
+/// Palettes of block states use 4 to 8 bits per entry, beyond that state ids are used directly.
+const MIN_INDIRECT_BITS: u8 = 4;
+const MAX_INDIRECT_BITS: u8 = 8;
+const DIRECT_BITS: u8 = 15;
+
+/// The block states of a chunk section, stored the way they are sent to clients so that
+/// sections of a single block take almost no memory and are written without repacking.
+/// Palettes only grow, like vanilla, until the section is rebuilt from its blocks.
+#[derive(Debug, Clone)]
+pub struct PalettedContainer {
+    storage: Storage,
+    /// The number of blocks that are not air, sent with every section.
+    non_air: u16,
+}
+
+#[derive(Debug, Clone)]
+enum Storage {
+    Single(u16),
+    Indirect {
+        bits: u8,
+        palette: Vec<u16>,
+        data: Vec<u64>,
+    },
+    Direct(Vec<u64>),
+}
+
+impl PalettedContainer {
+    /// A section filled with one block.
+    pub fn new(block: Block) -> Self {
+        Self {
+            storage: Storage::Single(block.get_state_id()),
+            non_air: if block.is_empty() {
+                0
+            } else {
+                BLOCKS_PER_SECTION as u16
+            },
+        }
+    }
+
+    /// Packs the blocks of a section, in the order of their index. Missing blocks are air.
+    pub fn from_blocks(blocks: &[Block]) -> Self {
+        let mut palette: Vec<u16> = vec![];
+        let mut indices: Vec<u16> = Vec::with_capacity(BLOCKS_PER_SECTION);
+        let mut non_air: u16 = 0;
+        let mut last: Option<(Block, u16)> = None;
+        for i in 0..BLOCKS_PER_SECTION {
+            let block: Block = blocks.get(i).copied().unwrap_or(Block::Air);
+            // Neighbouring blocks are usually the same
+            let index: u16 = match last {
+                Some((b, index)) if b == block => index,
+                _ => {
+                    let id: u16 = block.get_state_id();
+                    let index: usize = palette.iter().position(|&p| p == id).unwrap_or_else(|| {
+                        palette.push(id);
+                        palette.len() - 1
+                    });
+                    index as u16
+                }
+            };
+            last = Some((block, index));
+            indices.push(index);
+            if !block.is_empty() {
+                non_air += 1;
+            }
+        }
+
+        let storage: Storage = match palette.len() {
+            1 => Storage::Single(palette[0]),
+            length if length <= 1 << MAX_INDIRECT_BITS => {
+                let bits: u8 = bits_for(length).max(MIN_INDIRECT_BITS);
+                let mut data: Vec<u64> = vec![0; longs_for(bits)];
+                for (i, &index) in indices.iter().enumerate() {
+                    write(&mut data, bits, i, index);
+                }
+                Storage::Indirect {
+                    bits,
+                    palette,
+                    data,
+                }
+            }
+            _ => {
+                let mut data: Vec<u64> = vec![0; longs_for(DIRECT_BITS)];
+                for (i, &index) in indices.iter().enumerate() {
+                    write(&mut data, DIRECT_BITS, i, palette[index as usize]);
+                }
+                Storage::Direct(data)
+            }
+        };
+        Self { storage, non_air }
+    }
+
+    /// The state id of the block at an index.
+    pub fn get(&self, i: usize) -> u16 {
+        match &self.storage {
+            Storage::Single(id) => *id,
+            Storage::Indirect {
+                bits,
+                palette,
+                data,
+            } => palette[read(data, *bits, i) as usize],
+            Storage::Direct(data) => read(data, DIRECT_BITS, i),
+        }
+    }
+
+    /// Replaces the block at an index, growing the palette if needed, and returns the old one.
+    pub fn set(&mut self, i: usize, block: Block) -> Block {
+        let id: u16 = block.get_state_id();
+        let old: u16 = self.get(i);
+        if old == id {
+            return block;
+        }
+        self.set_id(i, id);
+
+        let old: Block = Block::from_state_id(old).unwrap_or(Block::Air);
+        match (old.is_empty(), block.is_empty()) {
+            (true, false) => self.non_air += 1,
+            (false, true) => self.non_air -= 1,
+            _ => {}
+        }
+        old
+    }
+
+    fn set_id(&mut self, i: usize, id: u16) {
+        match &mut self.storage {
+            Storage::Single(single) => {
+                let mut data: Vec<u64> = vec![0; longs_for(MIN_INDIRECT_BITS)];
+                write(&mut data, MIN_INDIRECT_BITS, i, 1);
+                self.storage = Storage::Indirect {
+                    bits: MIN_INDIRECT_BITS,
+                    palette: vec![*single, id],
+                    data,
+                };
+                return;
+            }
+            Storage::Indirect {
+                bits,
+                palette,
+                data,
+            } => {
+                if let Some(index) = palette.iter().position(|&p| p == id) {
+                    write(data, *bits, i, index as u16);
+                    return;
+                }
+                if palette.len() < 1 << *bits {
+                    palette.push(id);
+                    write(data, *bits, i, palette.len() as u16 - 1);
+                    return;
+                }
+            }
+            Storage::Direct(data) => {
+                write(data, DIRECT_BITS, i, id);
+                return;
+            }
+        }
+
+        // The palette is full
+        self.grow();
+        self.set_id(i, id);
+    }
+
+    /// Repacks the blocks with one more bit per entry, or with state ids past 8 bits.
+    fn grow(&mut self) {
+        self.storage = match &self.storage {
+            Storage::Indirect {
+                bits,
+                palette,
+                data,
+            } if *bits < MAX_INDIRECT_BITS => {
+                let mut grown: Vec<u64> = vec![0; longs_for(bits + 1)];
+                for i in 0..BLOCKS_PER_SECTION {
+                    write(&mut grown, bits + 1, i, read(data, *bits, i));
+                }
+                Storage::Indirect {
+                    bits: bits + 1,
+                    palette: palette.clone(),
+                    data: grown,
+                }
+            }
+            _ => {
+                let mut direct: Vec<u64> = vec![0; longs_for(DIRECT_BITS)];
+                for i in 0..BLOCKS_PER_SECTION {
+                    write(&mut direct, DIRECT_BITS, i, self.get(i));
+                }
+                Storage::Direct(direct)
+            }
+        };
+    }
+
+    /// The state ids of every block, in the order of their index.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        (0..BLOCKS_PER_SECTION).map(|i| self.get(i))
+    }
+
+    pub fn non_air_count(&self) -> u16 {
+        self.non_air
+    }
+
+    /// Writes the container as sent in Chunk Data, without the block count.
+    pub fn write_to(&self, d: &mut Vec<u8>) {
+        match &self.storage {
+            Storage::Single(id) => {
+                d.push(0);
+                d.write_varint(*id as i32);
+                d.write_varint(0);
+            }
+            Storage::Indirect {
+                bits,
+                palette,
+                data,
+            } => {
+                d.reserve(1 + 5 + palette.len() * 3 + 5 + data.len() * 8);
+                d.push(*bits);
+                d.write_varint(palette.len() as i32);
+                for &id in palette {
+                    d.write_varint(id as i32);
+                }
+                write_longs(d, data);
+            }
+            Storage::Direct(data) => {
+                d.reserve(1 + 5 + data.len() * 8);
+                d.push(DIRECT_BITS);
+                write_longs(d, data);
+            }
+        }
+    }
+}
+
+/// Containers are equal if they hold the same blocks, however they are packed.
+impl PartialEq for PalettedContainer {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.storage, &other.storage) {
+            (Storage::Single(a), Storage::Single(b)) => a == b,
+            _ => self.non_air == other.non_air && self.iter().eq(other.iter()),
+        }
+    }
+}
+impl Eq for PalettedContainer {}
+
+/// The bits needed to index a palette of this length.
+fn bits_for(length: usize) -> u8 {
+    (usize::BITS - length.saturating_sub(1).leading_zeros()) as u8
+}
+
+/// The longs holding every block at this many bits per entry. Entries do not span longs.
+fn longs_for(bits: u8) -> usize {
+    BLOCKS_PER_SECTION.div_ceil((u64::BITS / bits as u32) as usize)
+}
+
+fn read(data: &[u64], bits: u8, i: usize) -> u16 {
+    let per_long: usize = (u64::BITS / bits as u32) as usize;
+    (data[i / per_long] >> (bits as usize * (i % per_long)) & ((1 << bits) - 1)) as u16
+}
+
+fn write(data: &mut [u64], bits: u8, i: usize, value: u16) {
+    let per_long: usize = (u64::BITS / bits as u32) as usize;
+    let shift: usize = bits as usize * (i % per_long);
+    let long: &mut u64 = &mut data[i / per_long];
+    *long = *long & !(((1 << bits) - 1) << shift) | (value as u64) << shift;
+}
+
+fn write_longs(d: &mut Vec<u8>, data: &[u64]) {
+    d.write_varint(data.len() as i32);
+    for long in data {
+        d.extend_from_slice(&long.to_be_bytes());
+    }
+}
@@ -2,11 +2,14 @@ use std::string;
 
 use super::{Uuid, VarInt, WriteString, WriteVarInt};
 
+/// A player profile property: (name, value, signature).
+pub type Property = (string::String, string::String, Option<string::String>);
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum PlayerInfoUpdateActions {
     AddPlayer {
         name: string::String,
-        properties: Vec<(string::String, string::String, Option<string::String>)>,
+        properties: Vec<Property>,
     },
     InitializeChat {
         signature: Option<(Uuid, i64, Vec<u8>, Vec<u8>)>,
@@ -11,4 +11,13 @@ impl Position {
             | (self.y as i64 & 0xFFF))
             .to_be_bytes()
     }
+
+    /// Unpacks a position, sign-extending its 26-bit x and z and 12-bit y.
+    pub fn from_i64(value: i64) -> Self {
+        Self {
+            x: (value >> 38) as i32,
+            y: (value << 52 >> 52) as i16,
+            z: (value << 26 >> 38) as i32,
+        }
+    }
 }
@@ -15,12 +15,9 @@ use std::{
 };
 use thiserror::Error;
 
-use log::debug;
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
 
-use super::types::{Block, Chunk, ChunkSection, Dimension};
-
 pub trait GenUniqueKey<T> {
     fn gen_unique_key(&self) -> T;
 }
@@ -40,51 +37,30 @@ where
     }
 }
 
-pub fn get_chunk(chunks: &mut HashMap<(i32, i32), Chunk>, x: i32, z: i32) -> Chunk {
-    match chunks.get(&(x, z)) {
-        Some(chunk) => chunk.clone(),
-        None => {
-            let chunk = generate_chunk(x, z);
-            chunks.insert((x, z), chunk.clone());
-            chunk
-        }
-    }
-}
-
-pub fn generate_chunk(x: i32, z: i32) -> Chunk {
-    use Block::*;
-    let mut chunk: Chunk = Chunk {
-        dimension: Dimension::Overworld,
-        chunk_sections: Vec::with_capacity(24),
-    };
-
-    for section_y in 0..24 {
-        let mut chunk_section: ChunkSection = ChunkSection {
-            blocks: Vec::with_capacity(4096),
-        };
-        let mut push_layer = |block: Block| {
-            for _ in 0..256 {
-                chunk_section.blocks.push(block);
-            }
-        };
-
-        for block_y in 0..16 {
-            let y: i32 = section_y * 16 + block_y - 64;
-            if y == -64 {
-                push_layer(Bedrock);
-            } else if (-63..60).contains(&y) {
-                push_layer(Stone);
-            } else if (60..64).contains(&y) {
-                push_layer(Dirt);
-            } else if y == 64 {
-                push_layer(GrassBlock { snowy: false });
+/// Returns the chunks within `radius` of the center, spiraling outwards so that the closest are first.
+pub fn spiral_chunks(center_x: i32, center_z: i32, radius: i32) -> Vec<(i32, i32)> {
+    let count: usize = ((radius * 2 + 1) * (radius * 2 + 1)) as usize;
+    let mut chunks: Vec<(i32, i32)> = Vec::with_capacity(count);
+    let (mut x, mut z): (i32, i32) = (0, 0);
+    let (mut dx, mut dz): (i32, i32) = (1, 0);
+    let mut steps: i32 = 1;
+
+    // Every other turn the side of the spiral gets longer
+    while chunks.len() < count {
+        for _ in 0..2 {
+            for _ in 0..steps {
+                if x.abs() <= radius && z.abs() <= radius {
+                    chunks.push((center_x + x, center_z + z));
+                }
+                x += dx;
+                z += dz;
             }
+            (dx, dz) = (-dz, dx);
         }
-        chunk.chunk_sections.push(chunk_section);
+        steps += 1;
     }
 
-    debug!("Generated chunk {x} {z}");
-    chunk
+    chunks
 }
 
 /// Normalizes the yaw to be between -180 and 180 degrees.
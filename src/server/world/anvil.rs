@@ -0,0 +1,337 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+use eyre::{bail, ensure, eyre, Context, Result};
+use fastnbt::{LongArray, Value};
+use serde_derive::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::region::{region_file_name, RegionFile};
+use crate::server::types::{Biome, Block, Chunk, ChunkSection, Dimension, PalettedContainer};
+
+/// The data version of chunks saved by 1.20.1.
+pub const DATA_VERSION: i32 = 3465;
+/// The first data version with the chunk format of 1.18, which has no `Level` compound.
+const MIN_DATA_VERSION: i32 = 2844;
+const BLOCKS_PER_SECTION: usize = 4096;
+const BIOMES_PER_SECTION: usize = 64;
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+struct ChunkNbt {
+    #[serde(rename = "DataVersion")]
+    data_version: i32,
+    #[serde(rename = "xPos")]
+    x_pos: i32,
+    #[serde(rename = "zPos")]
+    z_pos: i32,
+    /// The lowest section.
+    #[serde(rename = "yPos")]
+    y_pos: Option<i32>,
+    #[serde(rename = "Status")]
+    status: String,
+    sections: Vec<SectionNbt>,
+    #[serde(rename = "Heightmaps")]
+    heightmaps: Option<Value>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+struct SectionNbt {
+    #[serde(rename = "Y")]
+    y: i8,
+    block_states: Option<PalettedContainerNbt<BlockStateNbt>>,
+    biomes: Option<PalettedContainerNbt<String>>,
+}
+
+/// Indices into the palette, packed into longs. Missing if the palette has a single entry.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug)]
+struct PalettedContainerNbt<T> {
+    palette: Vec<T>,
+    data: Option<LongArray>,
+}
+
+/// A block state in a palette, also used by structure files.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct BlockStateNbt {
+    #[serde(rename = "Name")]
+    pub(super) name: String,
+    #[serde(
+        rename = "Properties",
+        default,
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub(super) properties: HashMap<String, String>,
+}
+
+/// Serializes a chunk to the NBT stored in region files.
+pub fn serialize_chunk(chunk_x: i32, chunk_z: i32, chunk: &Chunk) -> Result<Vec<u8>> {
+    let min_section: i32 = chunk.dimension.min_height() / 16;
+
+    let mut sections: Vec<SectionNbt> = vec![];
+    for i in 0..chunk.dimension.chunk_section_count() {
+        let section: Option<&ChunkSection> = chunk.chunk_sections.get(i as usize);
+        let blocks: Vec<Block> = section
+            .map(|cs| cs.blocks.iter().map_while(Block::from_state_id).collect())
+            .unwrap_or_default();
+        let biomes: &[Biome] = section.map(|cs| cs.biomes.as_slice()).unwrap_or_default();
+
+        let (block_palette, block_indices) = palettize(&blocks, BLOCKS_PER_SECTION, Block::Air);
+        let (biome_palette, biome_indices) =
+            palettize(biomes, BIOMES_PER_SECTION, Biome::default());
+
+        sections.push(SectionNbt {
+            y: (min_section + i) as i8,
+            block_states: Some(PalettedContainerNbt {
+                data: pack(&block_indices, block_palette.len(), 4),
+                palette: block_palette
+                    .into_iter()
+                    .map(|block: Block| BlockStateNbt {
+                        name: format!("minecraft:{}", block.name()),
+                        properties: block
+                            .properties()
+                            .into_iter()
+                            .map(|(key, value)| (key.to_string(), value))
+                            .collect(),
+                    })
+                    .collect(),
+            }),
+            biomes: Some(PalettedContainerNbt {
+                data: pack(&biome_indices, biome_palette.len(), 0),
+                palette: biome_palette.iter().map(Biome::to_string).collect(),
+            }),
+        });
+    }
+
+    fastnbt::to_bytes(&ChunkNbt {
+        data_version: DATA_VERSION,
+        x_pos: chunk_x,
+        z_pos: chunk_z,
+        y_pos: Some(min_section),
+        status: "minecraft:full".to_string(),
+        sections,
+        heightmaps: Some(chunk.heightmaps.to_nbt(chunk)),
+    })
+    .wrap_err_with(|| format!("Could not serialize chunk {chunk_x} {chunk_z}"))
+}
+
+/// Deserializes a chunk from the NBT stored in region files.
+pub fn deserialize_chunk(nbt: &[u8], dimension: Dimension) -> Result<Chunk> {
+    let chunk: ChunkNbt = fastnbt::from_bytes(nbt).wrap_err("Invalid chunk NBT")?;
+    ensure!(
+        chunk.data_version >= MIN_DATA_VERSION,
+        "Chunk {} {} was saved by a version older than 1.18 (data version {})",
+        chunk.x_pos,
+        chunk.z_pos,
+        chunk.data_version
+    );
+
+    let min_section: i32 = dimension.min_height() / 16;
+    let mut chunk_sections: Vec<ChunkSection> = (0..dimension.chunk_section_count())
+        .map(|_| ChunkSection::new(vec![], Biome::default()))
+        .collect();
+
+    // Sections outside the height of the dimension only carry light
+    for section in chunk.sections {
+        let i: i32 = section.y as i32 - min_section;
+        let (Some(chunk_section), Some(block_states)) =
+            (chunk_sections.get_mut(i as usize), section.block_states)
+        else {
+            continue;
+        };
+
+        let palette: Vec<Block> = block_states
+            .palette
+            .iter()
+            .map(|state| Block::from_properties(&state.name, &state.properties))
+            .collect::<Result<_>>()
+            .wrap_err_with(|| {
+                format!(
+                    "Invalid palette in section {} of chunk {} {}",
+                    section.y, chunk.x_pos, chunk.z_pos
+                )
+            })?;
+        let indices: Vec<u64> = unpack(
+            block_states.data.as_deref(),
+            palette.len(),
+            4,
+            BLOCKS_PER_SECTION,
+        )?;
+
+        if let Some(biomes) = section.biomes {
+            let palette: Vec<Biome> = biomes
+                .palette
+                .iter()
+                .map(|name| name.parse())
+                .collect::<Result<_>>()
+                .wrap_err_with(|| {
+                    format!(
+                        "Invalid biome palette in section {} of chunk {} {}",
+                        section.y, chunk.x_pos, chunk.z_pos
+                    )
+                })?;
+            let indices: Vec<u64> =
+                unpack(biomes.data.as_deref(), palette.len(), 0, BIOMES_PER_SECTION)?;
+            for (biome, index) in chunk_section.biomes.iter_mut().zip(indices) {
+                *biome = *palette
+                    .get(index as usize)
+                    .ok_or_else(|| eyre!("Biome palette index {index} is out of bounds"))?;
+            }
+        }
+
+        let blocks: Vec<Block> = indices
+            .into_iter()
+            .map(|index| {
+                palette
+                    .get(index as usize)
+                    .copied()
+                    .ok_or_else(|| eyre!("Palette index {index} is out of bounds"))
+            })
+            .collect::<Result<_>>()?;
+        chunk_section.blocks = PalettedContainer::from_blocks(&blocks);
+    }
+
+    // Heightmaps are not read, in case the blocks were edited by other tools
+    Ok(Chunk::new(dimension, chunk_sections))
+}
+
+/// Returns the distinct values in order of appearance and the index of every value among them.
+/// Missing values are filled in with `default`.
+fn palettize<T: Copy + PartialEq>(values: &[T], length: usize, default: T) -> (Vec<T>, Vec<u64>) {
+    let mut palette: Vec<T> = vec![];
+    let mut indices: Vec<u64> = Vec::with_capacity(length);
+    for i in 0..length {
+        let value: T = values.get(i).copied().unwrap_or(default);
+        let index: usize = match palette.iter().position(|&v| v == value) {
+            Some(index) => index,
+            None => {
+                palette.push(value);
+                palette.len() - 1
+            }
+        };
+        indices.push(index as u64);
+    }
+    (palette, indices)
+}
+
+/// The bits per entry of a palette, which vanilla never makes smaller than `min_bits`.
+fn bits_per_entry(palette_length: usize, min_bits: u32) -> u32 {
+    (usize::BITS - palette_length.saturating_sub(1).leading_zeros()).max(min_bits)
+}
+
+/// Packs palette indices into longs. Entries do not span longs.
+fn pack(indices: &[u64], palette_length: usize, min_bits: u32) -> Option<LongArray> {
+    if palette_length <= 1 {
+        return None;
+    }
+    let bits: u32 = bits_per_entry(palette_length, min_bits);
+    let per_long: usize = (u64::BITS / bits) as usize;
+
+    let mut data: Vec<i64> = vec![0; indices.len().div_ceil(per_long)];
+    for (i, &index) in indices.iter().enumerate() {
+        data[i / per_long] |= (index << (bits as usize * (i % per_long))) as i64;
+    }
+    Some(LongArray::new(data))
+}
+
+fn unpack(
+    data: Option<&[i64]>,
+    palette_length: usize,
+    min_bits: u32,
+    length: usize,
+) -> Result<Vec<u64>> {
+    ensure!(palette_length > 0, "Palette is empty");
+    let Some(data) = data.filter(|_| palette_length > 1) else {
+        return Ok(vec![0; length]);
+    };
+    let bits: u32 = bits_per_entry(palette_length, min_bits);
+    let per_long: usize = (u64::BITS / bits) as usize;
+    ensure!(
+        data.len() == length.div_ceil(per_long),
+        "Expected {} longs of data, found {}",
+        length.div_ceil(per_long),
+        data.len()
+    );
+
+    let mask: u64 = (1 << bits) - 1;
+    Ok((0..length)
+        .map(|i| (data[i / per_long] as u64 >> (bits as usize * (i % per_long))) & mask)
+        .collect())
+}
+
+/// The region files of a dimension, in the layout of a vanilla world.
+pub struct WorldStorage {
+    directory: PathBuf,
+    dimension: Dimension,
+    regions: HashMap<(i32, i32), RegionFile<File>>,
+}
+impl WorldStorage {
+    /// Vanilla dimensions are stored where vanilla keeps them, others under `dimensions`.
+    pub fn new(world_directory: &Path, name: &str, dimension: Dimension) -> Result<Self> {
+        let directory: PathBuf = match name {
+            "minecraft:overworld" => world_directory.join("region"),
+            "minecraft:the_nether" => world_directory.join("DIM-1/region"),
+            "minecraft:the_end" => world_directory.join("DIM1/region"),
+            _ => {
+                let (namespace, path): (&str, &str) =
+                    name.split_once(':').unwrap_or(("minecraft", name));
+                world_directory
+                    .join("dimensions")
+                    .join(namespace)
+                    .join(path)
+                    .join("region")
+            }
+        };
+        fs::create_dir_all(&directory)
+            .wrap_err_with(|| format!("Could not create {}", directory.display()))?;
+
+        Ok(Self {
+            directory,
+            dimension,
+            regions: HashMap::new(),
+        })
+    }
+
+    /// Returns `None` if the chunk has not been saved.
+    pub fn load_chunk(&mut self, chunk_x: i32, chunk_z: i32) -> Result<Option<Chunk>> {
+        // Region files are only created when saving
+        if !self.regions.contains_key(&(chunk_x >> 5, chunk_z >> 5))
+            && !self
+                .directory
+                .join(region_file_name(chunk_x, chunk_z))
+                .exists()
+        {
+            return Ok(None);
+        }
+
+        match self
+            .region(chunk_x, chunk_z)?
+            .read_chunk(chunk_x, chunk_z)?
+        {
+            Some(nbt) => Ok(Some(deserialize_chunk(&nbt, self.dimension)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn save_chunk(&mut self, chunk_x: i32, chunk_z: i32, chunk: &Chunk) -> Result<()> {
+        if chunk.dimension != self.dimension {
+            bail!("Chunk {chunk_x} {chunk_z} is in another dimension");
+        }
+        let nbt: Vec<u8> = serialize_chunk(chunk_x, chunk_z, chunk)?;
+        self.region(chunk_x, chunk_z)?
+            .write_chunk(chunk_x, chunk_z, &nbt)
+    }
+
+    fn region(&mut self, chunk_x: i32, chunk_z: i32) -> Result<&mut RegionFile<File>> {
+        let key: (i32, i32) = (chunk_x >> 5, chunk_z >> 5);
+        if !self.regions.contains_key(&key) {
+            let path: PathBuf = self.directory.join(region_file_name(chunk_x, chunk_z));
+            self.regions.insert(key, RegionFile::open(&path)?);
+        }
+        Ok(self.regions.get_mut(&key).unwrap())
+    }
+}
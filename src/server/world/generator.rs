@@ -0,0 +1,254 @@
+use bracket_noise::prelude::FastNoise;
+use eyre::{ensure, Context, Result};
+use log::debug;
+
+use crate::server::{
+    seeded_noise,
+    types::{Biome, Block, Chunk, ChunkSection, Dimension, I0_15, I1_8},
+    SEED,
+};
+
+/// Blocks below this height are filled with water instead of air.
+pub const SEA_LEVEL: i32 = 63;
+/// The height of the terrain where the noise is 0.
+const BASE_HEIGHT: i32 = 64;
+/// How far hills rise above and valleys sink below the base height.
+const AMPLITUDE: f32 = 40.0;
+/// The depth of dirt below the surface.
+const DIRT_DEPTH: i32 = 3;
+/// Oceans deeper than this below sea level are deep oceans.
+const DEEP_OCEAN_DEPTH: i32 = 20;
+/// Temperatures range from about -1 to 1, below this biomes are snowy and above the next dry.
+const COLD: f32 = -0.2;
+const HOT: f32 = 0.2;
+/// The temperature is sampled far away from the heights, and varies more slowly.
+const TEMPERATURE_OFFSET: f32 = 10000.0;
+const TEMPERATURE_SCALE: f32 = 0.25;
+
+/// The layers of flat worlds when none are configured.
+pub const DEFAULT_FLAT_LAYERS: &str =
+    "minecraft:bedrock,123*minecraft:stone,4*minecraft:dirt,minecraft:grass_block";
+/// The height of the highest dimensions vanilla allows.
+const MAX_FLAT_HEIGHT: usize = 4064;
+
+/// Generates the chunks of a world that have not been saved yet.
+pub trait WorldGenerator: Send + Sync {
+    fn generate_chunk(&self, chunk_x: i32, chunk_z: i32, dimension: Dimension) -> Chunk;
+
+    /// Whether clients should render the world as superflat, with the horizon at y=0.
+    fn is_flat(&self) -> bool {
+        false
+    }
+}
+
+/// Layers of blocks from the bottom of the world, in a single biome, like vanilla superflat worlds.
+pub struct FlatGenerator {
+    layers: Vec<Block>,
+    biome: Biome,
+}
+impl FlatGenerator {
+    /// Parses layers from the bottom up in the syntax of vanilla presets, where blocks can be
+    /// repeated, like `minecraft:bedrock,2*minecraft:dirt,minecraft:grass_block`.
+    /// Layers above the top of the world are left out.
+    pub fn new(layers: &str, biome: Biome) -> Result<Self> {
+        let mut parsed: Vec<Block> = vec![];
+        for layer in layers.split(',').filter(|l| !l.trim().is_empty()) {
+            let (thickness, name): (usize, &str) = match layer.split_once('*') {
+                Some((thickness, name)) => (
+                    thickness
+                        .trim()
+                        .parse()
+                        .wrap_err_with(|| format!("Invalid thickness in {layer}"))?,
+                    name,
+                ),
+                None => (1, layer),
+            };
+            ensure!(
+                parsed.len() + thickness <= MAX_FLAT_HEIGHT,
+                "Superflat worlds have at most {MAX_FLAT_HEIGHT} layers"
+            );
+            parsed.extend(std::iter::repeat_n(Block::from_name(name)?, thickness));
+        }
+        Ok(Self {
+            layers: parsed,
+            biome,
+        })
+    }
+}
+/// Stone up to y=59, then dirt and grass at y=64 in the overworld.
+impl Default for FlatGenerator {
+    fn default() -> Self {
+        Self::new(DEFAULT_FLAT_LAYERS, Biome::Plains).unwrap()
+    }
+}
+impl WorldGenerator for FlatGenerator {
+    fn generate_chunk(&self, chunk_x: i32, chunk_z: i32, dimension: Dimension) -> Chunk {
+        let chunk: Chunk = build_chunk(
+            dimension,
+            |_, y, _| {
+                self.layers
+                    .get((y - dimension.min_height()) as usize)
+                    .copied()
+                    .unwrap_or(Block::Air)
+            },
+            |_, _| self.biome,
+        );
+
+        debug!("Generated flat chunk {chunk_x} {chunk_z}");
+        chunk
+    }
+
+    fn is_flat(&self) -> bool {
+        true
+    }
+}
+
+/// Hills shaped by a heightmap of noise, with water filling the valleys up to sea level.
+/// The same noise always gives the same terrain.
+pub struct NoiseGenerator {
+    noise: FastNoise,
+}
+impl NoiseGenerator {
+    pub fn new(noise: FastNoise) -> Self {
+        Self { noise }
+    }
+
+    /// The height of the surface at a block.
+    pub fn height_at(&self, x: i32, z: i32) -> i32 {
+        BASE_HEIGHT + (self.noise.get_noise(x as f32, z as f32) * AMPLITUDE) as i32
+    }
+
+    fn temperature_at(&self, x: i32, z: i32) -> f32 {
+        self.noise.get_noise(
+            x as f32 * TEMPERATURE_SCALE + TEMPERATURE_OFFSET,
+            z as f32 * TEMPERATURE_SCALE + TEMPERATURE_OFFSET,
+        )
+    }
+
+    /// The biome of a column of blocks, from its height and temperature.
+    pub fn biome_at(&self, x: i32, z: i32) -> Biome {
+        let height: i32 = self.height_at(x, z);
+        let temperature: f32 = self.temperature_at(x, z);
+        let (cold, hot): (bool, bool) = (temperature < COLD, temperature > HOT);
+
+        if height + 1 < SEA_LEVEL {
+            match (cold, hot) {
+                (true, _) => Biome::FrozenOcean,
+                (_, true) => Biome::WarmOcean,
+                _ if height + DEEP_OCEAN_DEPTH < SEA_LEVEL => Biome::DeepOcean,
+                _ => Biome::Ocean,
+            }
+        } else if height <= SEA_LEVEL + 1 {
+            match (cold, hot) {
+                (true, _) => Biome::SnowyBeach,
+                (_, true) => Biome::Desert,
+                _ => Biome::Beach,
+            }
+        } else {
+            match (cold, hot) {
+                (true, _) => Biome::SnowyPlains,
+                (_, true) => Biome::Desert,
+                _ => Biome::Plains,
+            }
+        }
+    }
+}
+impl Default for NoiseGenerator {
+    /// Uses noise seeded with `SEED`.
+    fn default() -> Self {
+        Self::new(seeded_noise(SEED))
+    }
+}
+impl WorldGenerator for NoiseGenerator {
+    fn generate_chunk(&self, chunk_x: i32, chunk_z: i32, dimension: Dimension) -> Chunk {
+        use Block::*;
+        let mut heights: [i32; 256] = [0; 256];
+        let mut biomes: [Biome; 256] = [Biome::Plains; 256];
+        for i in 0..256 {
+            let (x, z): (i32, i32) = (
+                chunk_x * 16 + (i % 16) as i32,
+                chunk_z * 16 + (i / 16) as i32,
+            );
+            heights[i] = self
+                .height_at(x, z)
+                .clamp(dimension.min_height() + 1, dimension.max_height() - 1);
+            biomes[i] = self.biome_at(x, z);
+        }
+
+        let chunk: Chunk = build_chunk(
+            dimension,
+            |x, y, z| {
+                let (height, biome): (i32, Biome) = (heights[x + z * 16], biomes[x + z * 16]);
+                let sandy: bool = matches!(
+                    biome,
+                    Biome::Desert | Biome::Beach | Biome::SnowyBeach | Biome::WarmOcean
+                );
+                let snowy: bool = matches!(biome, Biome::SnowyPlains | Biome::SnowyBeach);
+
+                if y == dimension.min_height() {
+                    Bedrock
+                } else if y < height - DIRT_DEPTH {
+                    Stone
+                } else if y < height {
+                    if sandy {
+                        Sand
+                    } else {
+                        Dirt
+                    }
+                } else if y == height {
+                    if sandy {
+                        Sand
+                    } else if height + 1 >= SEA_LEVEL {
+                        // Grass does not grow under water
+                        GrassBlock { snowy }
+                    } else {
+                        Dirt
+                    }
+                } else if y + 1 == SEA_LEVEL && biome == Biome::FrozenOcean {
+                    Ice
+                } else if y < SEA_LEVEL {
+                    Water {
+                        level: I0_15::new(0).unwrap(),
+                    }
+                } else if y == height + 1 && snowy {
+                    Snow {
+                        layers: I1_8::new(1).unwrap(),
+                    }
+                } else {
+                    Air
+                }
+            },
+            |x, z| biomes[x + z * 16],
+        );
+
+        debug!("Generated chunk {chunk_x} {chunk_z}");
+        chunk
+    }
+}
+
+/// Fills every section of a chunk with the block returned for its x, absolute y and z,
+/// and the biome returned for the center of every 4x4 column.
+fn build_chunk(
+    dimension: Dimension,
+    block_at: impl Fn(usize, i32, usize) -> Block,
+    biome_at: impl Fn(usize, usize) -> Biome,
+) -> Chunk {
+    let biomes: Vec<Biome> = (0..64)
+        .map(|i| biome_at(i % 4 * 4 + 2, i / 4 % 4 * 4 + 2))
+        .collect();
+    let mut chunk_sections: Vec<ChunkSection> =
+        Vec::with_capacity(dimension.chunk_section_count() as usize);
+    for section_y in 0..dimension.chunk_section_count() {
+        let blocks: Vec<Block> = (0..4096)
+            .map(|i| {
+                let y: i32 = dimension.min_height() + section_y * 16 + (i / 256) as i32;
+                block_at(i % 16, y, i / 16 % 16)
+            })
+            .collect();
+        let mut chunk_section: ChunkSection = ChunkSection::new(blocks, Biome::default());
+        chunk_section.biomes.clone_from(&biomes);
+        chunk_sections.push(chunk_section);
+    }
+
+    Chunk::new(dimension, chunk_sections)
+}
@@ -0,0 +1,367 @@
+//! Sky light and block light, spread through the sections of a chunk
+//! and across its sides into the loaded chunks around it.
+
+use std::collections::VecDeque;
+
+use lazy_static::lazy_static;
+
+use crate::server::types::{Block, Chunk};
+
+const MAX_LIGHT: u8 = 15;
+
+lazy_static! {
+    /// The opacity and emission of every block state, by state id.
+    static ref LIGHT_PROPERTIES: Vec<(u8, u8)> = (0..=u16::MAX)
+        .map_while(Block::from_state_id)
+        .map(|block: Block| (block.light_opacity(), block.light_emission()))
+        .collect();
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum LightKind {
+    Sky,
+    Block,
+}
+
+/// The light of a chunk, as sent in Chunk Data and Update Light.
+/// The masks have a bit for every section and the sections just below and above the world.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct LightData {
+    pub sky_light_mask: Vec<i64>,
+    pub block_light_mask: Vec<i64>,
+    pub empty_sky_light_mask: Vec<i64>,
+    pub empty_block_light_mask: Vec<i64>,
+    pub sky_light_arrays: Vec<[u8; 2048]>,
+    pub block_light_arrays: Vec<[u8; 2048]>,
+}
+impl LightData {
+    pub fn new(chunk: &Chunk) -> Self {
+        let sections: usize = chunk.chunk_sections.len();
+        let mut light_data: Self = Self {
+            sky_light_mask: vec![0; (sections + 2).div_ceil(64)],
+            ..Default::default()
+        };
+        light_data.block_light_mask = light_data.sky_light_mask.clone();
+        light_data.empty_sky_light_mask = light_data.sky_light_mask.clone();
+        light_data.empty_block_light_mask = light_data.sky_light_mask.clone();
+
+        let set = |mask: &mut Vec<i64>, i: usize| mask[i / 64] |= 1 << (i % 64);
+        // Nothing is lit below the world, and the sky is fully lit above it
+        set(&mut light_data.empty_sky_light_mask, 0);
+        set(&mut light_data.empty_block_light_mask, 0);
+        for (i, section) in chunk.chunk_sections.iter().enumerate() {
+            for (light, mask, empty_mask, arrays) in [
+                (
+                    &section.sky_light,
+                    &mut light_data.sky_light_mask,
+                    &mut light_data.empty_sky_light_mask,
+                    &mut light_data.sky_light_arrays,
+                ),
+                (
+                    &section.block_light,
+                    &mut light_data.block_light_mask,
+                    &mut light_data.empty_block_light_mask,
+                    &mut light_data.block_light_arrays,
+                ),
+            ] {
+                match <[u8; 2048]>::try_from(light.as_slice()) {
+                    Ok(array) if array.iter().any(|&l| l != 0) => {
+                        set(mask, i + 1);
+                        arrays.push(array);
+                    }
+                    _ => set(empty_mask, i + 1),
+                }
+            }
+        }
+        set(&mut light_data.sky_light_mask, sections + 1);
+        light_data.sky_light_arrays.push([0xFF; 2048]);
+        set(&mut light_data.empty_block_light_mask, sections + 1);
+
+        light_data
+    }
+}
+
+/// A chunk and the loaded chunks around it, which light spreads into.
+/// The chunk `dx` `dz` chunks from the one in the middle is at `dx + 1 + (dz + 1) * 3`.
+pub type Area<'a> = [Option<&'a mut Chunk>; 9];
+
+/// The index of the chunk in the middle of an area.
+pub const CENTER: usize = 4;
+
+/// Lights every block of a chunk from scratch, as if no chunk was loaded around it.
+pub fn light_chunk(chunk: &mut Chunk) {
+    let mut area: Area = Default::default();
+    area[CENTER] = Some(chunk);
+    for kind in [LightKind::Sky, LightKind::Block] {
+        let mut engine: Engine = Engine::new(&mut area, kind);
+        let mut queue: VecDeque<Pos> = VecDeque::new();
+        for y in 0..engine.height {
+            for i in 0..256 {
+                let p: Pos = (i % 16, y, i / 16);
+                let level: u8 = engine.source(p);
+                engine.set(p, level);
+                if level > 0 {
+                    queue.push_back(p);
+                }
+            }
+        }
+        engine.increase(queue);
+    }
+}
+
+/// Spreads light between the chunk in the middle, which was just lit on its own,
+/// and the chunks around it. The light of the chunk it `replaced` is taken out of them first.
+/// Returns which chunks of the area changed.
+pub fn join_light(area: &mut Area, replaced: Option<&Chunk>) -> [bool; 9] {
+    let mut changed: [bool; 9] = [false; 9];
+    for kind in [LightKind::Sky, LightKind::Block] {
+        let mut engine: Engine = Engine::new(area, kind);
+        let mut increase: VecDeque<Pos> = VecDeque::new();
+        for p in engine.border() {
+            // Light goes out through the blocks at the edge of the chunk, and in through those next to it
+            increase.push_back(p);
+            increase.extend(
+                engine
+                    .neighbours(p)
+                    .filter(|&(n, _)| index(n) != Some(CENTER))
+                    .map(|(n, _)| n),
+            );
+        }
+        if let Some(replaced) = replaced {
+            let removed: VecDeque<(Pos, u8)> = engine
+                .border()
+                .map(|p| (p, light_at(replaced, kind, p)))
+                .collect();
+            engine.decrease(removed, Some(CENTER), &mut increase);
+        }
+        engine.increase(increase);
+        changed = std::array::from_fn(|i| changed[i] || engine.changed[i]);
+    }
+    changed
+}
+
+/// Relights the chunk in the middle after one of its blocks changed, and the chunks around it,
+/// only touching the light that depends on the block. Returns which chunks of the area changed.
+pub fn update_light(area: &mut Area, x: u8, y: i32, z: u8) -> [bool; 9] {
+    let mut changed: [bool; 9] = [false; 9];
+    let Some(chunk) = &area[CENTER] else {
+        return changed;
+    };
+    let y: i32 = y - chunk.dimension.min_height();
+    if !(0..chunk.chunk_sections.len() as i32 * 16).contains(&y) {
+        return changed;
+    }
+    let p: Pos = (x as i32 & 0xF, y as usize, z as i32 & 0xF);
+
+    for kind in [LightKind::Sky, LightKind::Block] {
+        let mut engine: Engine = Engine::new(area, kind);
+        let mut increase: VecDeque<Pos> = VecDeque::new();
+        let removed: VecDeque<(Pos, u8)> = VecDeque::from([(p, engine.light(p))]);
+        engine.set(p, 0);
+        engine.relight_source(p, &mut increase);
+        engine.decrease(removed, None, &mut increase);
+        engine.increase(increase);
+        changed = std::array::from_fn(|i| changed[i] || engine.changed[i]);
+    }
+    changed
+}
+
+/// The x, height above the bottom of the world and z of a block,
+/// from the lowest corner of the chunk in the middle of an area.
+type Pos = (i32, usize, i32);
+
+/// The chunk of an area that a block is in, if it is in the area.
+fn index((x, _, z): Pos) -> Option<usize> {
+    let (dx, dz): (i32, i32) = (x.div_euclid(16), z.div_euclid(16));
+    ((-1..=1).contains(&dx) && (-1..=1).contains(&dz)).then_some((dx + 1 + (dz + 1) * 3) as usize)
+}
+
+/// The index of a block in the light of its section.
+fn light_index((x, y, z): Pos) -> usize {
+    x.rem_euclid(16) as usize + z.rem_euclid(16) as usize * 16 + (y % 16) * 256
+}
+
+fn light_at(chunk: &Chunk, kind: LightKind, p: Pos) -> u8 {
+    let section = &chunk.chunk_sections[p.1 / 16];
+    let light: &[u8] = match kind {
+        LightKind::Sky => &section.sky_light,
+        LightKind::Block => &section.block_light,
+    };
+    let i: usize = light_index(p);
+    light
+        .get(i / 2)
+        .map(|l| l >> (i % 2 * 4) & 0xF)
+        .unwrap_or(0)
+}
+
+struct Engine<'a, 'b> {
+    area: &'a mut Area<'b>,
+    kind: LightKind,
+    height: usize,
+    /// Which chunks of the area are loaded.
+    loaded: [bool; 9],
+    /// Which chunks of the area had their light changed.
+    changed: [bool; 9],
+}
+impl<'a, 'b> Engine<'a, 'b> {
+    fn new(area: &'a mut Area<'b>, kind: LightKind) -> Self {
+        let height: usize = area[CENTER]
+            .as_ref()
+            .map_or(0, |chunk| chunk.chunk_sections.len() * 16);
+        let loaded: [bool; 9] = std::array::from_fn(|i| area[i].is_some());
+        Self {
+            area,
+            kind,
+            height,
+            loaded,
+            changed: [false; 9],
+        }
+    }
+
+    fn chunk(&self, p: Pos) -> Option<&Chunk> {
+        self.area[index(p)?].as_deref()
+    }
+
+    fn light(&self, p: Pos) -> u8 {
+        self.chunk(p)
+            .map_or(0, |chunk| light_at(chunk, self.kind, p))
+    }
+
+    fn set(&mut self, p: Pos, level: u8) {
+        let Some(i) = index(p) else {
+            return;
+        };
+        let Some(chunk) = self.area[i].as_deref_mut() else {
+            return;
+        };
+        let section = &mut chunk.chunk_sections[p.1 / 16];
+        let light: &mut Vec<u8> = match self.kind {
+            LightKind::Sky => &mut section.sky_light,
+            LightKind::Block => &mut section.block_light,
+        };
+        light.resize(2048, 0);
+        let j: usize = light_index(p);
+        let shift: usize = j % 2 * 4;
+        if light[j / 2] >> shift & 0xF != level {
+            light[j / 2] = light[j / 2] & !(0xF << shift) | level << shift;
+            self.changed[i] = true;
+        }
+    }
+
+    /// The opacity and emission of a block.
+    fn properties(&self, p: Pos) -> (u8, u8) {
+        let Some(chunk) = self.chunk(p) else {
+            return (0, 0);
+        };
+        let id: u16 = chunk.chunk_sections[p.1 / 16].get_state_id(
+            p.0.rem_euclid(16) as u8,
+            (p.1 % 16) as u8,
+            p.2.rem_euclid(16) as u8,
+        );
+        LIGHT_PROPERTIES
+            .get(id as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The light of a block that does not come from its neighbours.
+    fn source(&self, p: Pos) -> u8 {
+        let (opacity, emission) = self.properties(p);
+        match self.kind {
+            // The top of the world is lit by the sky above it
+            LightKind::Sky if p.1 == self.height - 1 => spread(self.kind, MAX_LIGHT, opacity, true),
+            LightKind::Sky => 0,
+            LightKind::Block => emission,
+        }
+    }
+
+    /// The blocks next to a block in loaded chunks, and whether they are below it.
+    fn neighbours(&self, (x, y, z): Pos) -> impl Iterator<Item = (Pos, bool)> {
+        let (height, loaded): (usize, [bool; 9]) = (self.height, self.loaded);
+        [
+            (Some((x - 1, y, z)), false),
+            (Some((x + 1, y, z)), false),
+            (Some((x, y, z - 1)), false),
+            (Some((x, y, z + 1)), false),
+            (y.checked_sub(1).map(|y| (x, y, z)), true),
+            ((y + 1 < height).then_some((x, y + 1, z)), false),
+        ]
+        .into_iter()
+        .filter_map(move |(p, down)| {
+            p.filter(|&p| index(p).is_some_and(|i| loaded[i]))
+                .map(|p| (p, down))
+        })
+    }
+
+    /// The blocks at the sides of the chunk in the middle.
+    fn border(&self) -> impl Iterator<Item = Pos> {
+        let height: usize = self.height;
+        (0..height)
+            .flat_map(|y| (0..16).flat_map(move |i| [(i, y, 0), (i, y, 15), (0, y, i), (15, y, i)]))
+    }
+
+    /// Spreads light from the queued blocks to every block it can brighten.
+    fn increase(&mut self, mut queue: VecDeque<Pos>) {
+        while let Some(p) = queue.pop_front() {
+            let level: u8 = self.light(p);
+            if level == 0 {
+                continue;
+            }
+            for (n, down) in self.neighbours(p) {
+                let level: u8 = spread(self.kind, level, self.properties(n).0, down);
+                if level > self.light(n) {
+                    self.set(n, level);
+                    queue.push_back(n);
+                }
+            }
+        }
+    }
+
+    /// Removes the light that may have come from the removed light of the queued blocks,
+    /// leaving the chunk at `keep` as it is, and queues the blocks that light has to be spread
+    /// from again.
+    fn decrease(
+        &mut self,
+        mut queue: VecDeque<(Pos, u8)>,
+        keep: Option<usize>,
+        increase: &mut VecDeque<Pos>,
+    ) {
+        while let Some((p, level)) = queue.pop_front() {
+            for (n, down) in self.neighbours(p) {
+                if keep.is_some() && index(n) == keep {
+                    continue;
+                }
+                let neighbour_level: u8 = self.light(n);
+                if neighbour_level == 0 {
+                    continue;
+                }
+                if neighbour_level <= spread(self.kind, level, self.properties(n).0, down) {
+                    // The light may have come from the removed light
+                    self.set(n, 0);
+                    queue.push_back((n, neighbour_level));
+                    self.relight_source(n, increase);
+                } else {
+                    increase.push_back(n);
+                }
+            }
+        }
+    }
+
+    fn relight_source(&mut self, p: Pos, increase: &mut VecDeque<Pos>) {
+        let level: u8 = self.source(p);
+        if level > 0 {
+            self.set(p, level);
+            increase.push_back(p);
+        }
+    }
+}
+
+/// The light of a block next to a block with `level`.
+/// Sky light keeps its full level going straight down through transparent blocks.
+fn spread(kind: LightKind, level: u8, opacity: u8, down: bool) -> u8 {
+    if kind == LightKind::Sky && down && level == MAX_LIGHT && opacity == 0 {
+        MAX_LIGHT
+    } else {
+        level.saturating_sub(opacity.max(1))
+    }
+}
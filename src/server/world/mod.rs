@@ -0,0 +1,586 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
+    mem,
+    ops::RangeInclusive,
+    path::Path,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use eyre::Result;
+use log::warn;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use super::{
+    net::encode_chunk_data,
+    types::{Block, Chunk, Dimension},
+};
+use anvil::WorldStorage;
+use generator::WorldGenerator;
+use light::{join_light, light_chunk, update_light, Area};
+use schematic::Schematic;
+
+pub mod anvil;
+pub mod generator;
+pub mod light;
+pub mod region;
+pub mod schematic;
+
+/// The chunks of a dimension, loaded from and saved to region files if it has storage.
+/// Every world is a dimension of its own name, so several can share a dimension type.
+///
+/// Chunks are loaded or generated and encoded for Chunk Data on blocking threads, a few at a time,
+/// so that the tick loop only has to ask for them and send them once they are ready.
+/// Blocks of chunks that are not loaded, and pasted blocks, are changed by the same workers.
+/// Light spreads between loaded chunks, so chunks are only encoded once the chunks around them
+/// are loaded too, and those are kept while a player has the chunk loaded.
+/// Chunks that no player has had loaded for a while are dropped, once workers saved them if they
+/// changed. Chunks that changed are never dropped without storage.
+pub struct World {
+    /// The name of the dimension, like `minecraft:overworld`.
+    name: String,
+    dimension: Dimension,
+    chunks: HashMap<(i32, i32), LoadedChunk>,
+    storage: Option<Arc<Mutex<WorldStorage>>>,
+    generator: Arc<dyn WorldGenerator>,
+    /// Chunks waiting for a worker, in the order they were requested.
+    queue: VecDeque<(i32, i32)>,
+    /// Chunks that are queued or being worked on.
+    requested: HashSet<(i32, i32)>,
+    /// Block changes waiting for a worker, in the order they were made.
+    pending: HashMap<(i32, i32), Vec<BlockChange>>,
+    /// Chunks whose blocks are being changed by a worker, which can only change after it.
+    editing: HashSet<(i32, i32)>,
+    /// Chunks whose blocks were changed by a worker, or whose light changed with them.
+    changed: HashSet<(i32, i32)>,
+    /// Chunks waiting for a worker to save them, after the chunks that were requested.
+    save_queue: VecDeque<(i32, i32)>,
+    /// Chunks that are queued to be saved or being saved.
+    saving: HashSet<(i32, i32)>,
+    in_flight: usize,
+    max_in_flight: usize,
+    job_sender: UnboundedSender<ChunkJob>,
+    job_receiver: UnboundedReceiver<ChunkJob>,
+    save_sender: UnboundedSender<SaveJob>,
+    save_receiver: UnboundedReceiver<SaveJob>,
+}
+
+struct LoadedChunk {
+    chunk: Chunk,
+    /// Changed by every block update, so that chunks encoded before it are discarded.
+    version: u64,
+    /// Chunk Data, encoded since the last block update.
+    encoded: Option<Arc<Vec<u8>>>,
+    /// The players that have the chunk loaded.
+    viewers: usize,
+    /// When the chunk was loaded, or last stopped being viewed.
+    unviewed_since: Instant,
+    /// Whether the chunk changed since it was last saved.
+    dirty: bool,
+}
+impl LoadedChunk {
+    fn new(chunk: Chunk) -> Self {
+        Self {
+            chunk,
+            version: 0,
+            encoded: None,
+            viewers: 0,
+            unviewed_since: Instant::now(),
+            dirty: false,
+        }
+    }
+}
+
+/// The x, y and z of a block in its chunk, and the block it is replaced with.
+type BlockChange = (u8, i32, u8, Block);
+
+/// A chunk loaded, changed or encoded by a worker.
+struct ChunkJob {
+    chunk_x: i32,
+    chunk_z: i32,
+    /// `None` if the chunk was already loaded and only needed encoding.
+    /// Chunks that were loaded or changed are not encoded, as their light changes with the chunks
+    /// around them.
+    chunk: Option<Chunk>,
+    /// Whether blocks of the chunk were changed.
+    changed: bool,
+    version: u64,
+    encoded: Option<Arc<Vec<u8>>>,
+}
+
+/// A chunk saved by a worker.
+struct SaveJob {
+    chunk_x: i32,
+    chunk_z: i32,
+    /// The version of the chunk that was saved.
+    version: u64,
+    saved: bool,
+}
+
+impl World {
+    /// A world that is only kept in memory.
+    pub fn new(name: &str, dimension: Dimension, generator: Box<dyn WorldGenerator>) -> Self {
+        let (job_sender, job_receiver) = unbounded_channel();
+        let (save_sender, save_receiver) = unbounded_channel();
+        Self {
+            name: name.to_string(),
+            dimension,
+            chunks: HashMap::new(),
+            storage: None,
+            generator: Arc::from(generator),
+            queue: VecDeque::new(),
+            requested: HashSet::new(),
+            pending: HashMap::new(),
+            editing: HashSet::new(),
+            changed: HashSet::new(),
+            save_queue: VecDeque::new(),
+            saving: HashSet::new(),
+            in_flight: 0,
+            max_in_flight: thread::available_parallelism().map_or(4, |n| n.get()),
+            job_sender,
+            job_receiver,
+            save_sender,
+            save_receiver,
+        }
+    }
+
+    pub fn open(
+        directory: &Path,
+        name: &str,
+        dimension: Dimension,
+        generator: Box<dyn WorldGenerator>,
+    ) -> Result<Self> {
+        Ok(Self {
+            storage: Some(Arc::new(Mutex::new(WorldStorage::new(
+                directory, name, dimension,
+            )?))),
+            ..Self::new(name, dimension, generator)
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn dimension(&self) -> Dimension {
+        self.dimension
+    }
+
+    pub fn is_flat(&self) -> bool {
+        self.generator.is_flat()
+    }
+
+    /// Returns `None` if the chunk is not loaded.
+    pub fn get_chunk(&self, x: i32, z: i32) -> Option<&Chunk> {
+        self.chunks.get(&(x, z)).map(|loaded| &loaded.chunk)
+    }
+
+    /// Returns the encoded Chunk Data of a chunk if it is ready,
+    /// otherwise queues the chunk and the chunks around it to be loaded, then the chunk to be
+    /// encoded, by a worker.
+    pub fn get_chunk_data(&mut self, x: i32, z: i32) -> Option<Arc<Vec<u8>>> {
+        if let Some(encoded) = self.chunks.get(&(x, z)).and_then(|c| c.encoded.clone()) {
+            return Some(encoded);
+        }
+        let mut missing: Vec<(i32, i32)> = area_positions(x, z)
+            .into_iter()
+            .filter(|position| !self.chunks.contains_key(position))
+            .collect();
+        if missing.is_empty() {
+            self.request(x, z);
+        }
+        // The chunk itself is loaded first
+        missing.sort_by_key(|&position| position != (x, z));
+        for (x, z) in missing {
+            self.request(x, z);
+        }
+        None
+    }
+
+    fn request(&mut self, x: i32, z: i32) {
+        if self.requested.insert((x, z)) {
+            self.queue.push_back((x, z));
+            self.start_jobs();
+        }
+    }
+
+    /// Stores the chunks finished by workers and starts the next ones.
+    /// Returns the chunks that workers finished with, which the players waiting for them have to
+    /// ask for again, as chunks that changed while they were encoded, or failed to, are queued
+    /// again when they are asked for.
+    pub fn receive_chunks(&mut self) -> Vec<(i32, i32)> {
+        let mut received: Vec<(i32, i32)> = vec![];
+        while let Ok(job) = self.job_receiver.try_recv() {
+            self.in_flight -= 1;
+            let (x, z): (i32, i32) = (job.chunk_x, job.chunk_z);
+            self.requested.remove(&(x, z));
+            self.editing.remove(&(x, z));
+
+            match job.chunk {
+                Some(chunk) => {
+                    let replaced: Option<Chunk> = match self.chunks.entry((x, z)) {
+                        Entry::Occupied(entry) => {
+                            let loaded: &mut LoadedChunk = entry.into_mut();
+                            loaded.version += 1;
+                            loaded.encoded = None;
+                            loaded.dirty |= job.changed;
+                            Some(mem::replace(&mut loaded.chunk, chunk))
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(LoadedChunk {
+                                dirty: job.changed,
+                                ..LoadedChunk::new(chunk)
+                            });
+                            None
+                        }
+                    };
+                    let relit: Vec<(i32, i32)> =
+                        self.light_area(x, z, |area| join_light(area, replaced.as_ref()));
+                    if job.changed {
+                        self.changed.insert((x, z));
+                        self.changed.extend(relit);
+                    }
+                }
+                None => {
+                    if let Some(loaded) = self.chunks.get_mut(&(x, z)) {
+                        if loaded.version == job.version && job.encoded.is_some() {
+                            loaded.encoded = job.encoded;
+                        }
+                    }
+                }
+            }
+            // Blocks changed while the worker was busy are changed by the next one
+            if self.pending.contains_key(&(x, z)) {
+                self.request(x, z);
+            }
+            received.push((x, z));
+        }
+        while let Ok(job) = self.save_receiver.try_recv() {
+            self.in_flight -= 1;
+            self.saving.remove(&(job.chunk_x, job.chunk_z));
+            // Chunks that changed while they were saved, or failed to save, are saved again later
+            if let Some(loaded) = self.chunks.get_mut(&(job.chunk_x, job.chunk_z)) {
+                if job.saved && loaded.version == job.version {
+                    loaded.dirty = false;
+                }
+            }
+        }
+        self.start_jobs();
+        received
+    }
+
+    /// Takes the chunks whose blocks were changed by workers, or whose light changed with them,
+    /// since it was last called. Players that have them loaded have to be sent them again.
+    pub fn take_changed(&mut self) -> Vec<(i32, i32)> {
+        self.changed.drain().collect()
+    }
+
+    fn start_jobs(&mut self) {
+        while self.in_flight < self.max_in_flight {
+            let Some((x, z)) = self.queue.pop_front() else {
+                match self.save_queue.pop_front() {
+                    Some((x, z)) => {
+                        self.start_save(x, z);
+                        continue;
+                    }
+                    None => break,
+                }
+            };
+            self.in_flight += 1;
+
+            // Loaded chunks are copied, so that they can change while they are encoded
+            let (chunk, version): (Option<Chunk>, u64) = match self.chunks.get(&(x, z)) {
+                Some(loaded) => (Some(loaded.chunk.clone()), loaded.version),
+                None => (None, 0),
+            };
+            // The blocks of the chunk can only change again once the worker is done
+            let changes: Vec<BlockChange> = self.pending.remove(&(x, z)).unwrap_or_default();
+            if !changes.is_empty() {
+                self.editing.insert((x, z));
+            }
+            let dimension: Dimension = self.dimension;
+            let storage: Option<Arc<Mutex<WorldStorage>>> = self.storage.clone();
+            let generator: Arc<dyn WorldGenerator> = self.generator.clone();
+            let job_sender: UnboundedSender<ChunkJob> = self.job_sender.clone();
+            tokio::task::spawn_blocking(move || {
+                let job: ChunkJob = match chunk {
+                    Some(chunk) if changes.is_empty() => ChunkJob {
+                        chunk_x: x,
+                        chunk_z: z,
+                        chunk: None,
+                        changed: false,
+                        version,
+                        encoded: match encode_chunk_data(&chunk) {
+                            Ok(encoded) => Some(Arc::new(encoded)),
+                            Err(e) => {
+                                warn!("Could not encode chunk {x} {z}: {e:#}");
+                                None
+                            }
+                        },
+                    },
+                    chunk => {
+                        let mut chunk: Chunk = chunk.unwrap_or_else(|| {
+                            load_chunk(x, z, dimension, storage.as_deref(), generator.as_ref())
+                        });
+                        let mut changed: bool = false;
+                        for (x, y, z, block) in changes {
+                            changed |= chunk.set_block(x, y, z, block);
+                        }
+                        if changed {
+                            light_chunk(&mut chunk);
+                        }
+                        ChunkJob {
+                            chunk_x: x,
+                            chunk_z: z,
+                            chunk: Some(chunk),
+                            changed,
+                            version,
+                            encoded: None,
+                        }
+                    }
+                };
+                // The world is gone if the server stopped
+                let _ = job_sender.send(job);
+            });
+        }
+    }
+
+    fn start_save(&mut self, x: i32, z: i32) {
+        let (Some(storage), Some(loaded)) = (self.storage.clone(), self.chunks.get(&(x, z))) else {
+            self.saving.remove(&(x, z));
+            return;
+        };
+        self.in_flight += 1;
+
+        // Chunks are copied, so that they can change while they are saved
+        let chunk: Chunk = loaded.chunk.clone();
+        let version: u64 = loaded.version;
+        let save_sender: UnboundedSender<SaveJob> = self.save_sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let saved: Result<()> = storage
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .save_chunk(x, z, &chunk);
+            if let Err(e) = &saved {
+                warn!("Could not save chunk {x} {z}: {e:#}");
+            }
+            // The world is gone if the server stopped
+            let _ = save_sender.send(SaveJob {
+                chunk_x: x,
+                chunk_z: z,
+                version,
+                saved: saved.is_ok(),
+            });
+        });
+    }
+
+    /// Returns `None` if the block is out of bounds or its chunk is not loaded.
+    /// Blocks changed by workers only change once they are done.
+    pub fn get_block(&self, x: i32, y: i32, z: i32) -> Option<Block> {
+        self.get_chunk(x.div_euclid(16), z.div_euclid(16))?
+            .get_block(x.rem_euclid(16) as u8, y, z.rem_euclid(16) as u8)
+    }
+
+    /// Replaces a block and relights its chunk and those around it if the chunk is loaded,
+    /// otherwise queues the change for a worker. Returns `false` if the block is out of bounds.
+    pub fn set_block(&mut self, x: i32, y: i32, z: i32, block: Block) -> bool {
+        if !self.heights().contains(&y) {
+            return false;
+        }
+        let (chunk_x, chunk_z): (i32, i32) = (x.div_euclid(16), z.div_euclid(16));
+        let (x, z): (u8, u8) = (x.rem_euclid(16) as u8, z.rem_euclid(16) as u8);
+        // Changes are made in order, after those waiting for a worker
+        if self.editing.contains(&(chunk_x, chunk_z))
+            || self.pending.contains_key(&(chunk_x, chunk_z))
+        {
+            self.change_later(chunk_x, chunk_z, (x, y, z, block));
+            return true;
+        }
+        let Some(loaded) = self.chunks.get_mut(&(chunk_x, chunk_z)) else {
+            self.change_later(chunk_x, chunk_z, (x, y, z, block));
+            return true;
+        };
+
+        loaded.chunk.set_block(x, y, z, block);
+        loaded.version += 1;
+        loaded.encoded = None;
+        loaded.dirty = true;
+        self.light_area(chunk_x, chunk_z, |area| update_light(area, x, y, z));
+        true
+    }
+
+    /// Pastes a schematic with its lowest corner at `origin`. Its blocks are changed by workers,
+    /// which relight every chunk it changes once. Blocks outside the height of the world are left
+    /// out. Returns the chunks that change.
+    pub fn paste(&mut self, schematic: &Schematic, origin: (i32, i32, i32)) -> Vec<(i32, i32)> {
+        let heights: RangeInclusive<i32> = self.heights();
+        let mut changed: HashSet<(i32, i32)> = HashSet::new();
+        for &((x, y, z), block) in &schematic.blocks {
+            let (x, y, z): (i32, i32, i32) = (origin.0 + x, origin.1 + y, origin.2 + z);
+            if heights.contains(&y) {
+                let (chunk_x, chunk_z): (i32, i32) = (x.div_euclid(16), z.div_euclid(16));
+                self.change_later(
+                    chunk_x,
+                    chunk_z,
+                    (x.rem_euclid(16) as u8, y, z.rem_euclid(16) as u8, block),
+                );
+                changed.insert((chunk_x, chunk_z));
+            }
+        }
+        changed.into_iter().collect()
+    }
+
+    fn heights(&self) -> RangeInclusive<i32> {
+        self.dimension.min_height()..=self.dimension.max_height()
+    }
+
+    /// Queues a block change for a worker.
+    fn change_later(&mut self, x: i32, z: i32, change: BlockChange) {
+        self.pending.entry((x, z)).or_default().push(change);
+        self.request(x, z);
+    }
+
+    /// Runs the light engine on a loaded chunk and the loaded chunks around it.
+    /// Returns the chunks whose light changed, which have to be encoded again.
+    fn light_area(
+        &mut self,
+        x: i32,
+        z: i32,
+        light: impl FnOnce(&mut Area) -> [bool; 9],
+    ) -> Vec<(i32, i32)> {
+        let positions: [(i32, i32); 9] = area_positions(x, z);
+        let mut loaded: [Option<&mut LoadedChunk>; 9] =
+            self.chunks.get_disjoint_mut(positions.each_ref());
+        let mut area: Area = loaded
+            .each_mut()
+            .map(|loaded| loaded.as_deref_mut().map(|loaded| &mut loaded.chunk));
+        let changed: [bool; 9] = light(&mut area);
+
+        let mut relit: Vec<(i32, i32)> = vec![];
+        for ((position, loaded), changed) in positions.into_iter().zip(loaded).zip(changed) {
+            if let (Some(loaded), true) = (loaded, changed) {
+                loaded.version += 1;
+                loaded.encoded = None;
+                relit.push(position);
+            }
+        }
+        relit
+    }
+
+    /// Counts a player that was sent a loaded chunk, keeping it in memory.
+    pub fn add_viewer(&mut self, x: i32, z: i32) {
+        if let Some(loaded) = self.chunks.get_mut(&(x, z)) {
+            loaded.viewers += 1;
+        }
+    }
+
+    /// Counts a player that unloaded a chunk, or left with it loaded.
+    pub fn remove_viewer(&mut self, x: i32, z: i32) {
+        if let Some(loaded) = self.chunks.get_mut(&(x, z)) {
+            loaded.viewers = loaded.viewers.saturating_sub(1);
+            if loaded.viewers == 0 {
+                loaded.unviewed_since = Instant::now();
+            }
+        }
+    }
+
+    /// Drops the chunks that no player has had loaded for `delay`. Those that changed are queued to
+    /// be saved by a worker instead, and dropped once they are saved, or kept without storage.
+    /// Chunks that are being worked on, or that light a chunk a player has loaded, are kept.
+    /// Returns the number of chunks dropped.
+    pub fn unload_chunks(&mut self, delay: Duration) -> usize {
+        let unused: Vec<(i32, i32)> = self
+            .chunks
+            .iter()
+            .filter(|&(&(x, z), loaded)| {
+                loaded.unviewed_since.elapsed() >= delay
+                    && !self.requested.contains(&(x, z))
+                    && !self.saving.contains(&(x, z))
+                    && area_positions(x, z)
+                        .iter()
+                        .all(|position| self.chunks.get(position).is_none_or(|c| c.viewers == 0))
+            })
+            .map(|(&position, _)| position)
+            .collect();
+        let mut dropped: usize = 0;
+        for (x, z) in unused {
+            if !self.chunks[&(x, z)].dirty {
+                self.chunks.remove(&(x, z));
+                dropped += 1;
+            } else if self.storage.is_some() {
+                self.save(x, z);
+            }
+        }
+        self.start_jobs();
+        dropped
+    }
+
+    /// Queues every chunk that changed since it was saved to be saved by a worker.
+    pub fn save_chunks(&mut self) {
+        if self.storage.is_none() {
+            return;
+        }
+        let dirty: Vec<(i32, i32)> = self
+            .chunks
+            .iter()
+            .filter(|(_, loaded)| loaded.dirty)
+            .map(|(&position, _)| position)
+            .collect();
+        for (x, z) in dirty {
+            self.save(x, z);
+        }
+        self.start_jobs();
+    }
+
+    fn save(&mut self, x: i32, z: i32) {
+        if self.saving.insert((x, z)) {
+            self.save_queue.push_back((x, z));
+        }
+    }
+
+    /// The number of chunks in memory.
+    pub fn loaded_chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+}
+
+/// The positions of a chunk and the chunks around it, in the order of an [`Area`].
+fn area_positions(x: i32, z: i32) -> [(i32, i32); 9] {
+    std::array::from_fn(|i| (x + i as i32 % 3 - 1, z + i as i32 / 3 - 1))
+}
+
+/// Loads a chunk from storage, or generates and saves it if it does not exist yet, and lights it.
+fn load_chunk(
+    x: i32,
+    z: i32,
+    dimension: Dimension,
+    storage: Option<&Mutex<WorldStorage>>,
+    generator: &dyn WorldGenerator,
+) -> Chunk {
+    let loaded: Option<Result<Option<Chunk>>> =
+        storage.map(|s| s.lock().unwrap_or_else(|e| e.into_inner()).load_chunk(x, z));
+    let mut chunk: Chunk = match loaded {
+        Some(Ok(Some(chunk))) => chunk,
+        // Unreadable chunks are regenerated, but not overwritten
+        Some(Err(e)) => {
+            warn!("Could not load chunk {x} {z}: {e:#}");
+            generator.generate_chunk(x, z, dimension)
+        }
+        Some(Ok(None)) => {
+            let chunk: Chunk = generator.generate_chunk(x, z, dimension);
+            let saved: Result<()> = storage
+                .unwrap()
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .save_chunk(x, z, &chunk);
+            if let Err(e) = saved {
+                warn!("Could not save chunk {x} {z}: {e:#}");
+            }
+            chunk
+        }
+        None => generator.generate_chunk(x, z, dimension),
+    };
+    light_chunk(&mut chunk);
+    chunk
+}
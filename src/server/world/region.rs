@@ -0,0 +1,205 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use eyre::{bail, ensure, Context, Result};
+use flate2::{
+    read::{GzDecoder, ZlibDecoder},
+    write::ZlibEncoder,
+    Compression,
+};
+
+pub const SECTOR_SIZE: usize = 4096;
+/// The locations and timestamps of the chunks.
+const HEADER_SECTORS: usize = 2;
+/// The sector count of a location is a single byte.
+const MAX_CHUNK_SECTORS: usize = 255;
+
+const GZIP: u8 = 1;
+const ZLIB: u8 = 2;
+const UNCOMPRESSED: u8 = 3;
+/// Set on the compression type of chunks too large for the region, which are in a `.mcc` file.
+const EXTERNAL: u8 = 128;
+
+/// A region file (`r.<x>.<z>.mca`) holding 32x32 chunks in 4 KiB sectors.
+pub struct RegionFile<F: Read + Write + Seek> {
+    file: F,
+    /// The first sector and sector count of every chunk, or 0 if it has not been saved.
+    locations: [u32; 1024],
+    timestamps: [u32; 1024],
+    used_sectors: Vec<bool>,
+}
+impl RegionFile<File> {
+    /// Opens the region file at `path`, creating it if it does not exist.
+    pub fn open(path: &Path) -> Result<Self> {
+        let file: File = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .wrap_err_with(|| format!("Could not open {}", path.display()))?;
+        Self::new(file).wrap_err_with(|| format!("Could not read {}", path.display()))
+    }
+}
+impl<F: Read + Write + Seek> RegionFile<F> {
+    pub fn new(mut file: F) -> Result<Self> {
+        let length: usize = file.seek(SeekFrom::End(0))? as usize;
+
+        let mut locations: [u32; 1024] = [0; 1024];
+        let mut timestamps: [u32; 1024] = [0; 1024];
+        if length < HEADER_SECTORS * SECTOR_SIZE {
+            // New or truncated files start out empty
+            file.seek(SeekFrom::Start(0))?;
+            file.write_all(&[0; HEADER_SECTORS * SECTOR_SIZE])?;
+        } else {
+            let mut header: Vec<u8> = vec![0; HEADER_SECTORS * SECTOR_SIZE];
+            file.seek(SeekFrom::Start(0))?;
+            file.read_exact(&mut header)?;
+            for i in 0..1024 {
+                locations[i] = u32::from_be_bytes(header[i * 4..i * 4 + 4].try_into()?);
+                timestamps[i] = u32::from_be_bytes(
+                    header[SECTOR_SIZE + i * 4..SECTOR_SIZE + i * 4 + 4].try_into()?,
+                );
+            }
+        }
+
+        let mut used_sectors: Vec<bool> = vec![false; length.div_ceil(SECTOR_SIZE)];
+        used_sectors.resize(used_sectors.len().max(HEADER_SECTORS), false);
+        used_sectors[..HEADER_SECTORS].fill(true);
+        for location in &mut locations {
+            let (offset, count) = (*location as usize >> 8, *location as usize & 0xFF);
+            // Chunks overlapping the header or past the end of the file are treated as missing
+            if offset < HEADER_SECTORS || offset + count > used_sectors.len() {
+                *location = 0;
+                continue;
+            }
+            used_sectors[offset..offset + count].fill(true);
+        }
+
+        Ok(Self {
+            file,
+            locations,
+            timestamps,
+            used_sectors,
+        })
+    }
+
+    /// Returns the uncompressed NBT of a chunk, or `None` if it has not been saved.
+    /// Coordinates are taken modulo 32.
+    pub fn read_chunk(&mut self, x: i32, z: i32) -> Result<Option<Vec<u8>>> {
+        let location: u32 = self.locations[index(x, z)];
+        if location == 0 {
+            return Ok(None);
+        }
+        let (offset, count) = (location as usize >> 8, location as usize & 0xFF);
+
+        let mut data: Vec<u8> = vec![0; count * SECTOR_SIZE];
+        self.file
+            .seek(SeekFrom::Start((offset * SECTOR_SIZE) as u64))?;
+        self.file.read_exact(&mut data)?;
+
+        let length: usize = u32::from_be_bytes(data[0..4].try_into()?) as usize;
+        ensure!(
+            (1..=data.len() - 4).contains(&length),
+            "Invalid length of chunk {x} {z}"
+        );
+        let compression: u8 = data[4];
+        let compressed: &[u8] = &data[5..4 + length];
+
+        let mut nbt: Vec<u8> = vec![];
+        match compression {
+            GZIP => {
+                GzDecoder::new(compressed).read_to_end(&mut nbt)?;
+            }
+            ZLIB => {
+                ZlibDecoder::new(compressed).read_to_end(&mut nbt)?;
+            }
+            UNCOMPRESSED => nbt.extend_from_slice(compressed),
+            c if c & EXTERNAL != 0 => bail!("Chunk {x} {z} is in an external file"),
+            c => bail!("Unsupported compression type {c} of chunk {x} {z}"),
+        }
+        Ok(Some(nbt))
+    }
+
+    /// Compresses and saves the NBT of a chunk. Coordinates are taken modulo 32.
+    pub fn write_chunk(&mut self, x: i32, z: i32, nbt: &[u8]) -> Result<()> {
+        let mut encoder: ZlibEncoder<Vec<u8>> = ZlibEncoder::new(vec![], Compression::default());
+        encoder.write_all(nbt)?;
+        let compressed: Vec<u8> = encoder.finish()?;
+
+        let mut data: Vec<u8> = Vec::with_capacity(5 + compressed.len());
+        data.extend_from_slice(&(compressed.len() as u32 + 1).to_be_bytes());
+        data.push(ZLIB);
+        data.extend_from_slice(&compressed);
+        let count: usize = data.len().div_ceil(SECTOR_SIZE);
+        ensure!(
+            count <= MAX_CHUNK_SECTORS,
+            "Chunk {x} {z} is too large ({} bytes)",
+            data.len()
+        );
+        data.resize(count * SECTOR_SIZE, 0);
+
+        // The old sectors are only freed once the header points to the new ones
+        let offset: usize = self.allocate(count);
+        self.file
+            .seek(SeekFrom::Start((offset * SECTOR_SIZE) as u64))?;
+        self.file.write_all(&data)?;
+
+        let i: usize = index(x, z);
+        let old_location: u32 = self.locations[i];
+        self.locations[i] = (offset as u32) << 8 | count as u32;
+        self.timestamps[i] = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as u32)
+            .unwrap_or_default();
+        self.write_header(i)?;
+
+        if old_location != 0 {
+            let (offset, count) = (old_location as usize >> 8, old_location as usize & 0xFF);
+            self.used_sectors[offset..offset + count].fill(false);
+        }
+        Ok(())
+    }
+
+    /// Returns the first of `count` free sectors, growing the file if there are none.
+    fn allocate(&mut self, count: usize) -> usize {
+        let mut run: usize = 0;
+        for (i, &used) in self.used_sectors.iter().enumerate() {
+            run = if used { 0 } else { run + 1 };
+            if run == count {
+                let offset: usize = i + 1 - count;
+                self.used_sectors[offset..=i].fill(true);
+                return offset;
+            }
+        }
+
+        // Free sectors at the end of the file are reused
+        let offset: usize = self.used_sectors.len() - run;
+        self.used_sectors.resize(offset + count, true);
+        self.used_sectors[offset..].fill(true);
+        offset
+    }
+
+    fn write_header(&mut self, i: usize) -> Result<()> {
+        self.file.seek(SeekFrom::Start(i as u64 * 4))?;
+        self.file.write_all(&self.locations[i].to_be_bytes())?;
+        self.file
+            .seek(SeekFrom::Start((SECTOR_SIZE + i * 4) as u64))?;
+        self.file.write_all(&self.timestamps[i].to_be_bytes())?;
+        self.file.flush()?;
+        Ok(())
+    }
+}
+
+/// The name of the region file holding a chunk.
+pub fn region_file_name(chunk_x: i32, chunk_z: i32) -> String {
+    format!("r.{}.{}.mca", chunk_x >> 5, chunk_z >> 5)
+}
+
+fn index(x: i32, z: i32) -> usize {
+    (x & 31) as usize + (z & 31) as usize * 32
+}
@@ -0,0 +1,228 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    io::Read,
+    path::Path,
+};
+
+use eyre::{bail, ensure, eyre, Context, Result};
+use fastnbt::ByteArray;
+use flate2::read::GzDecoder;
+use log::warn;
+use serde_derive::Deserialize;
+
+use super::anvil::BlockStateNbt;
+use crate::server::types::{Block, ReadVarInt};
+
+/// Schematics and structures are usually saved with gzip.
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+
+/// Blocks read from a Sponge schematic or a vanilla structure file, to be pasted into worlds.
+#[derive(Debug)]
+pub struct Schematic {
+    /// The width, height and length, along x, y and z.
+    pub size: (i32, i32, i32),
+    /// Blocks by their position from the lowest corner. Structures can leave positions out,
+    /// which keep the blocks already in the world, and blocks unknown to this version are left out.
+    pub blocks: Vec<((i32, i32, i32), Block)>,
+}
+
+#[derive(Deserialize, Debug)]
+struct SpongeFileNbt {
+    /// Version 3 wraps the schematic in a compound.
+    #[serde(rename = "Schematic")]
+    schematic: Option<SpongeNbt>,
+}
+
+#[derive(Deserialize, Debug)]
+struct SpongeNbt {
+    #[serde(rename = "Version")]
+    version: i32,
+    /// Unsigned.
+    #[serde(rename = "Width")]
+    width: i16,
+    #[serde(rename = "Height")]
+    height: i16,
+    #[serde(rename = "Length")]
+    length: i16,
+    /// Versions 1 and 2.
+    #[serde(rename = "Palette")]
+    palette: Option<HashMap<String, i32>>,
+    #[serde(rename = "BlockData")]
+    block_data: Option<ByteArray>,
+    /// Version 3.
+    #[serde(rename = "Blocks")]
+    blocks: Option<SpongeBlocksNbt>,
+}
+
+#[derive(Deserialize, Debug)]
+struct SpongeBlocksNbt {
+    #[serde(rename = "Palette")]
+    palette: HashMap<String, i32>,
+    /// Palette indices as VarInts, ordered by y, then z, then x.
+    #[serde(rename = "Data")]
+    data: ByteArray,
+}
+
+#[derive(Deserialize, Debug)]
+struct StructureNbt {
+    size: Vec<i32>,
+    palette: Option<Vec<BlockStateNbt>>,
+    /// Some structures, like shipwrecks, have several palettes to pick from.
+    palettes: Option<Vec<Vec<BlockStateNbt>>>,
+    blocks: Vec<StructureBlockNbt>,
+}
+
+#[derive(Deserialize, Debug)]
+struct StructureBlockNbt {
+    state: i32,
+    pos: Vec<i32>,
+}
+
+impl Schematic {
+    /// Reads a `.schem` Sponge schematic or a `.nbt` structure, compressed or not.
+    pub fn load(path: &Path) -> Result<Self> {
+        let file: Vec<u8> =
+            fs::read(path).wrap_err_with(|| format!("Could not read {}", path.display()))?;
+        let nbt: Vec<u8> = if file.starts_with(&GZIP_MAGIC) {
+            let mut nbt: Vec<u8> = vec![];
+            GzDecoder::new(file.as_slice()).read_to_end(&mut nbt)?;
+            nbt
+        } else {
+            file
+        };
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("schem") => Self::from_sponge(&nbt),
+            Some("nbt") => Self::from_structure(&nbt),
+            _ => bail!("Schematics must be .schem or .nbt files"),
+        }
+        .wrap_err_with(|| format!("Invalid schematic {}", path.display()))
+    }
+
+    /// Reads the NBT of a Sponge schematic, of version 1 to 3.
+    pub fn from_sponge(nbt: &[u8]) -> Result<Self> {
+        let schematic: SpongeNbt = match fastnbt::from_bytes::<SpongeFileNbt>(nbt)?.schematic {
+            Some(schematic) => schematic,
+            None => fastnbt::from_bytes(nbt)?,
+        };
+        let size: (i32, i32, i32) = (
+            schematic.width as u16 as i32,
+            schematic.height as u16 as i32,
+            schematic.length as u16 as i32,
+        );
+        let (palette, data): (HashMap<String, i32>, ByteArray) = match schematic {
+            SpongeNbt {
+                version: 1 | 2,
+                palette: Some(palette),
+                block_data: Some(data),
+                ..
+            } => (palette, data),
+            SpongeNbt {
+                version: 3,
+                blocks: Some(blocks),
+                ..
+            } => (blocks.palette, blocks.data),
+            // Only biomes or entities
+            SpongeNbt { version: 3, .. } => {
+                return Ok(Self {
+                    size,
+                    blocks: vec![],
+                })
+            }
+            SpongeNbt { version, .. } => bail!("Unsupported Sponge schematic version {version}"),
+        };
+
+        let palette: HashMap<i32, Option<Block>> = palette
+            .into_iter()
+            .map(|(state, index)| match state.parse() {
+                Ok(block) => (index, Some(block)),
+                Err(e) => {
+                    warn!("Leaving out {state} from the schematic: {e:#}");
+                    (index, None)
+                }
+            })
+            .collect();
+        let mut data: VecDeque<u8> = data.iter().map(|&b| b as u8).collect();
+        let (width, height, length): (u64, u64, u64) =
+            (size.0 as u64, size.1 as u64, size.2 as u64);
+        let volume: u64 = width
+            .checked_mul(height)
+            .and_then(|area| area.checked_mul(length))
+            .ok_or_else(|| eyre!("Size {width}x{height}x{length} is too large"))?;
+        // Every block takes at least a byte
+        ensure!(
+            volume <= data.len() as u64,
+            "Block data is too short for {width}x{height}x{length} blocks"
+        );
+        let mut blocks: Vec<((i32, i32, i32), Block)> = Vec::with_capacity(volume as usize);
+        for i in 0..volume {
+            let index: i32 = data.read_varint().wrap_err("Block data is too short")?;
+            let block: Option<Block> = *palette
+                .get(&index)
+                .ok_or_else(|| eyre!("Palette index {index} is out of bounds"))?;
+            if let Some(block) = block {
+                let position: (u64, u64, u64) =
+                    (i % width, i / (width * length), i / width % length);
+                blocks.push((
+                    (position.0 as i32, position.1 as i32, position.2 as i32),
+                    block,
+                ));
+            }
+        }
+        ensure!(
+            data.is_empty(),
+            "Block data is longer than {width}x{height}x{length} blocks"
+        );
+
+        Ok(Self { size, blocks })
+    }
+
+    /// Reads the NBT of a structure saved by a structure block, with its first palette.
+    pub fn from_structure(nbt: &[u8]) -> Result<Self> {
+        let structure: StructureNbt = fastnbt::from_bytes(nbt)?;
+        let [width, height, length] = structure.size[..] else {
+            bail!("Invalid size {:?}", structure.size);
+        };
+        let palette: Vec<BlockStateNbt> = match (structure.palette, structure.palettes) {
+            (Some(palette), _) => palette,
+            (None, Some(palettes)) => palettes.into_iter().next().unwrap_or_default(),
+            (None, None) => bail!("Structure has no palette"),
+        };
+
+        let palette: Vec<Option<Block>> = palette
+            .iter()
+            .map(
+                |state| match Block::from_properties(&state.name, &state.properties) {
+                    Ok(block) => Some(block),
+                    Err(e) => {
+                        warn!("Leaving out {} from the structure: {e:#}", state.name);
+                        None
+                    }
+                },
+            )
+            .collect();
+        let mut blocks: Vec<((i32, i32, i32), Block)> = Vec::with_capacity(structure.blocks.len());
+        for StructureBlockNbt { state, pos } in structure.blocks {
+            let [x, y, z] = pos[..] else {
+                bail!("Invalid block position {pos:?}");
+            };
+            ensure!(
+                (0..width).contains(&x) && (0..height).contains(&y) && (0..length).contains(&z),
+                "Block at {x} {y} {z} is outside the structure"
+            );
+            let block: Option<Block> = *usize::try_from(state)
+                .ok()
+                .and_then(|i| palette.get(i))
+                .ok_or_else(|| eyre!("Palette index {state} is out of bounds"))?;
+            if let Some(block) = block {
+                blocks.push(((x, y, z), block));
+            }
+        }
+
+        Ok(Self {
+            size: (width, height, length),
+            blocks,
+        })
+    }
+}
@@ -1,6 +1,64 @@
-use std::collections::VecDeque;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs,
+    future::Future,
+    io::Cursor,
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
 
-use crate::server::types::{ReadVarInt, WriteVarInt, WriteVarLong};
+use eyre::Result;
+use fastnbt::nbt;
+use hmac::{Hmac, Mac};
+use rand::thread_rng;
+use rsa::{pkcs8::DecodePublicKey, Pkcs1v15Encrypt, RsaPublicKey};
+use sha2::Sha256;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::{
+    config::{ConnectionLimits, SendQueueLimits, SlowClientPolicy},
+    server::{
+        auth::{server_hash, Authenticator, GameProfile, SessionVerifier},
+        break_time,
+        entity_ids::EntityIds,
+        forwarding::{parse_bungeecord, parse_velocity},
+        net::{
+            connection_manager::{
+                proxy_protocol::read_proxy_header,
+                send_manager::Queue,
+                throttle::{Rejection, Throttle},
+                types::SendQueue,
+            },
+            encode_chunk_data,
+            encryption::{EncryptedReader, EncryptedWriter},
+            prefix_with_length,
+            recording::{read_recording_header, Direction, Record, RECORDING_MAGIC},
+            IncomingPacket, OutgoingPacket, ReadPacket, RespawnData, WritePacket,
+        },
+        protocol_mismatch, seeded_noise,
+        state::ConnectionState,
+        types::{
+            Axis, Biome, Block, BlockFace, Chunk, ChunkSection, DiggingStatus, Dimension,
+            DoorHinge, DoubleBlockHalf, EntityMetadata, EntityMetadataField, Gamemode, Half,
+            Heightmaps, HorizontalFacing, ItemInfo, PalettedContainer, PlayerInfoUpdateActions,
+            Position, ReadVarInt, RedstoneWireConnection, SlabType, StairsShape, Uuid, WriteString,
+            WriteVarInt, WriteVarLong, I0_15, I1_7,
+        },
+        util::spiral_chunks,
+        world::{
+            anvil::{deserialize_chunk, serialize_chunk},
+            generator::{FlatGenerator, NoiseGenerator, WorldGenerator, SEA_LEVEL},
+            light::{light_chunk, LightData},
+            region::RegionFile,
+            schematic::Schematic,
+            World,
+        },
+        BREAK_TIME_TOLERANCE, SEED,
+    },
+};
 
 #[test]
 fn test_varint() {
@@ -77,3 +135,1551 @@ fn test_varlong() {
         assert_eq!(buf, results[i], "test #{}", i);
     }
 }
+
+#[tokio::test]
+async fn test_compression() {
+    // (threshold, whether the packet ends up compressed)
+    let cases: Vec<(i32, bool)> = vec![(-1, false), (0, true), (256, false)];
+    for (threshold, compressed) in cases {
+        let mut buf: Vec<u8> = vec![];
+        buf.write_packet(OutgoingPacket::KeepAlive { keep_alive_id: 42 }, threshold)
+            .await
+            .unwrap();
+
+        let frame: Vec<u8> = buf.as_slice().read_frame().await.unwrap();
+        if threshold >= 0 {
+            assert_eq!(frame[0] != 0, compressed, "threshold {}", threshold);
+        }
+
+        // Keep Alive is 0x23 clientbound, which is unknown serverbound
+        match IncomingPacket::decode(frame, &ConnectionState::Play, threshold).unwrap() {
+            IncomingPacket::Unknown { data } => {
+                assert_eq!(data, 42i64.to_be_bytes(), "threshold {}", threshold)
+            }
+            p => panic!("Unexpected packet {p}"),
+        }
+    }
+}
+
+#[test]
+fn test_server_hash() {
+    // Examples from https://wiki.vg/Protocol_Encryption
+    let cases: Vec<(&str, &str)> = vec![
+        ("Notch", "4ed1f46bbe04bc756bcb17c0c7ce3e4632f06a48"),
+        ("jeb_", "-7c9d5b0044c130109a5d7b5fb5c317c02b4e28c1"),
+        ("simon", "88e16a1019277b15d58faf0541e11910eb756f6"),
+    ];
+    for (name, hash) in cases {
+        assert_eq!(server_hash(name, &[], &[]), hash, "{}", name);
+    }
+}
+
+#[tokio::test]
+async fn test_encryption() {
+    let shared_secret: [u8; 16] = *b"0123456789abcdef";
+    let message: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+    let mut encrypted: Vec<u8> = vec![];
+    let mut writer: EncryptedWriter<&mut Vec<u8>> = EncryptedWriter::new(&mut encrypted);
+    writer.write_all(b"plain").await.unwrap();
+    writer.enable_encryption(&shared_secret);
+    writer.write_all(message).await.unwrap();
+    writer.write_all(message).await.unwrap();
+    writer.flush().await.unwrap();
+    drop(writer);
+    assert_eq!(&encrypted[..5], b"plain");
+    assert_ne!(&encrypted[5..5 + message.len()], message);
+
+    let mut reader: EncryptedReader<&[u8]> = EncryptedReader::new(encrypted.as_slice());
+    let mut plain: [u8; 5] = [0; 5];
+    reader.read_exact(&mut plain).await.unwrap();
+    assert_eq!(&plain, b"plain");
+    reader.enable_encryption(&shared_secret);
+    let mut decrypted: Vec<u8> = vec![];
+    reader.read_to_end(&mut decrypted).await.unwrap();
+    assert_eq!(decrypted, [message, message].concat());
+}
+
+#[tokio::test]
+async fn test_encryption_backpressure() {
+    let shared_secret: [u8; 16] = *b"0123456789abcdef";
+    let message: Vec<u8> = (0..100_000).map(|i| i as u8).collect();
+
+    let (client, server) = tokio::io::duplex(1024);
+    let mut writer: EncryptedWriter<tokio::io::DuplexStream> = EncryptedWriter::new(server);
+    writer.enable_encryption(&shared_secret);
+    let sent: Vec<u8> = message.clone();
+    let write = tokio::spawn(async move {
+        writer.write_all(&sent).await?;
+        writer.flush().await
+    });
+    // Nothing is read yet, so the writer cannot take the whole message
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(!write.is_finished());
+
+    let mut reader: EncryptedReader<tokio::io::DuplexStream> = EncryptedReader::new(client);
+    reader.enable_encryption(&shared_secret);
+    let mut decrypted: Vec<u8> = vec![0; message.len()];
+    reader.read_exact(&mut decrypted).await.unwrap();
+    write.await.unwrap().unwrap();
+    assert_eq!(decrypted, message);
+}
+
+/// Stands in for the session server and accepts a single player.
+struct FakeSessionVerifier;
+impl SessionVerifier for FakeSessionVerifier {
+    fn has_joined<'a>(
+        &'a self,
+        username: &'a str,
+        server_hash: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<GameProfile>>> + Send + 'a>> {
+        Box::pin(async move {
+            // The hash is passed back in the name, so that the test can check it
+            Ok((username == "tester").then(|| GameProfile {
+                uuid: Uuid(1),
+                name: format!("{username} {server_hash}"),
+                properties: vec![("textures".to_string(), "skin".to_string(), None)],
+            }))
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_authentication() {
+    let shared_secret: [u8; 16] = *b"0123456789abcdef";
+    let verify_token: [u8; 4] = Authenticator::gen_verify_token();
+    let authenticator: Authenticator = Authenticator::new(Arc::new(FakeSessionVerifier)).unwrap();
+
+    // What the client does with the Encryption Request
+    let public_key: RsaPublicKey =
+        RsaPublicKey::from_public_key_der(&authenticator.public_key).unwrap();
+    let encrypt = |data: &[u8]| {
+        public_key
+            .encrypt(&mut thread_rng(), Pkcs1v15Encrypt, data)
+            .unwrap()
+    };
+
+    assert_eq!(
+        authenticator
+            .decrypt_shared_secret(
+                &encrypt(&shared_secret),
+                &encrypt(&verify_token),
+                &verify_token
+            )
+            .unwrap(),
+        shared_secret
+    );
+    assert!(authenticator
+        .decrypt_shared_secret(&encrypt(&shared_secret), &encrypt(&[0; 4]), &[1; 4])
+        .is_err());
+
+    let profile: GameProfile = authenticator
+        .verify("tester".to_string(), shared_secret)
+        .await
+        .unwrap();
+    assert_eq!(profile.uuid, Uuid(1));
+    assert_eq!(
+        profile.name,
+        format!(
+            "tester {}",
+            server_hash("", &shared_secret, &authenticator.public_key)
+        )
+    );
+    assert!(authenticator
+        .verify("someone".to_string(), shared_secret)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_legacy_server_list_ping() {
+    // 1.6 clients send 0xFE 0x01 0xFA followed by a plugin message
+    let mut legacy: &[u8] = &[0xFE, 0x01, 0xFA];
+    assert_eq!(legacy.read_handshake_frame().await.unwrap(), None);
+
+    let mut handshake: Vec<u8> = vec![];
+    handshake.write_varint(0x00);
+    handshake.write_varint(763);
+    let mut framed: Vec<u8> = handshake.clone();
+    prefix_with_length(&mut framed);
+    assert_eq!(
+        framed.as_slice().read_handshake_frame().await.unwrap(),
+        Some(handshake)
+    );
+
+    let mut buf: Vec<u8> = vec![];
+    buf.write_packet(
+        OutgoingPacket::LegacyServerListPingResponse {
+            protocol_version: 763,
+            server_version: "1.20.1".to_string(),
+            motd: "A Minecraft Server".to_string(),
+            online: 1,
+            max: 20,
+        },
+        -1,
+    )
+    .await
+    .unwrap();
+    let reason: String = ["§1", "763", "1.20.1", "A Minecraft Server", "1", "20"].join("\0");
+    assert_eq!(buf[0], 0xFF);
+    assert_eq!(
+        u16::from_be_bytes([buf[1], buf[2]]) as usize,
+        reason.encode_utf16().count()
+    );
+    assert_eq!(
+        buf[3..]
+            .chunks(2)
+            .map(|c| u16::from_be_bytes([c[0], c[1]]))
+            .collect::<Vec<u16>>(),
+        reason.encode_utf16().collect::<Vec<u16>>()
+    );
+}
+
+#[test]
+fn test_throttle() {
+    let mut throttle: Throttle = Throttle::new(
+        ConnectionLimits {
+            max_connections: 3,
+            max_connections_per_ip: 2,
+            min_connection_interval: 0,
+            max_handshake_failures: 2,
+            ban_duration: 60,
+        },
+        false,
+    );
+    let a: IpAddr = [10, 0, 0, 1].into();
+    let b: IpAddr = [10, 0, 0, 2].into();
+    let c: IpAddr = [10, 0, 0, 3].into();
+
+    assert_eq!(throttle.try_accept(0, a), Ok(()));
+    assert_eq!(throttle.try_accept(1, a), Ok(()));
+    assert_eq!(
+        throttle.try_accept(2, a),
+        Err(Rejection::TooManyConnectionsFromAddress)
+    );
+    assert_eq!(throttle.try_accept(3, b), Ok(()));
+    assert_eq!(
+        throttle.try_accept(4, c),
+        Err(Rejection::TooManyConnections)
+    );
+
+    throttle.release(3, b, false);
+    assert_eq!(throttle.try_accept(5, c), Ok(()));
+
+    // Two failed handshakes in a row get an address banned
+    throttle.release(0, a, true);
+    throttle.release(1, a, true);
+    assert_eq!(throttle.try_accept(6, a), Err(Rejection::Banned));
+
+    // A successful handshake resets the count
+    throttle.release(5, c, true);
+    assert_eq!(throttle.try_accept(7, c), Ok(()));
+    throttle.release(7, c, false);
+    assert_eq!(throttle.try_accept(8, c), Ok(()));
+    throttle.release(8, c, true);
+    assert_eq!(throttle.try_accept(9, c), Ok(()));
+}
+
+#[test]
+fn test_throttle_interval() {
+    let mut throttle: Throttle = Throttle::new(
+        ConnectionLimits {
+            min_connection_interval: 60000,
+            ..Default::default()
+        },
+        false,
+    );
+    let a: IpAddr = [10, 0, 0, 1].into();
+
+    assert_eq!(throttle.try_accept(0, a), Ok(()));
+    throttle.release(0, a, false);
+    assert_eq!(throttle.try_accept(1, a), Err(Rejection::TooFrequent));
+    assert_eq!(throttle.try_accept(2, [10, 0, 0, 2].into()), Ok(()));
+}
+
+#[test]
+fn test_throttle_forwarding() {
+    let mut throttle: Throttle = Throttle::new(
+        ConnectionLimits {
+            max_connections_per_ip: 1,
+            min_connection_interval: 60000,
+            max_handshake_failures: 1,
+            ..Default::default()
+        },
+        true,
+    );
+    let proxy: IpAddr = [10, 0, 0, 1].into();
+    let a: IpAddr = [10, 0, 0, 2].into();
+    let b: IpAddr = [10, 0, 0, 3].into();
+
+    // Every player connects from the proxy, which only the forwarded addresses are limited by
+    assert_eq!(throttle.try_accept(0, proxy), Ok(()));
+    assert_eq!(throttle.try_accept(1, proxy), Ok(()));
+    assert_eq!(throttle.readdress(0, a), Ok(()));
+    assert_eq!(throttle.readdress(1, a), Err(Rejection::TooFrequent));
+    assert_eq!(throttle.readdress(1, b), Ok(()));
+    throttle.release(1, proxy, false);
+
+    // Failed handshakes ban the client instead of the proxy
+    throttle.release(0, proxy, true);
+    assert_eq!(throttle.try_accept(2, proxy), Ok(()));
+    assert_eq!(throttle.readdress(2, a), Err(Rejection::Banned));
+}
+
+fn chunk_packet(chunk_x: i32, chunk_z: i32) -> OutgoingPacket {
+    OutgoingPacket::ChunkDataAndUpdateLight {
+        chunk_x,
+        chunk_z,
+        data: Arc::new(vec![]),
+    }
+}
+
+#[test]
+fn test_send_queue_defer() {
+    let limits: SendQueueLimits = SendQueueLimits {
+        capacity: 8,
+        high_water_mark: 2,
+        slow_clients: SlowClientPolicy::Defer,
+        max_deferred: 3,
+    };
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(limits.capacity);
+    let send_queue: Arc<SendQueue> = Arc::new(SendQueue::default());
+    let mut queue: Queue = Queue::new(sender, send_queue.clone());
+    let keep_alive = |keep_alive_id: i64| OutgoingPacket::KeepAlive { keep_alive_id };
+
+    // Past the high-water mark, chunks wait while everything else goes through
+    assert!(queue.send(keep_alive(0), &limits));
+    assert!(queue.send(chunk_packet(0, 0), &limits));
+    assert!(queue.send(keep_alive(1), &limits));
+    assert!(queue.send(chunk_packet(1, 0), &limits));
+    assert!(queue.send(chunk_packet(2, 0), &limits));
+    assert!(queue.send(keep_alive(2), &limits));
+    assert_eq!(send_queue.depth(), 4);
+    // A block of a deferred chunk is updated after it, and others right away
+    let block_update = |x: i32| OutgoingPacket::BlockUpdate {
+        location: Position { x, y: 64, z: 0 },
+        block_id: 1,
+    };
+    assert!(queue.send(block_update(40), &limits));
+    assert!(queue.send(block_update(-1), &limits));
+    // A deferred chunk that is unloaded is never sent
+    assert!(queue.send(
+        OutgoingPacket::UnloadChunk {
+            chunk_x: 1,
+            chunk_z: 0
+        },
+        &limits
+    ));
+    assert!(!send_queue.is_too_slow());
+
+    let mut sent: Vec<OutgoingPacket> = vec![];
+    while let Ok(packet) = receiver.try_recv() {
+        send_queue.pop();
+        sent.push(packet);
+    }
+    assert_eq!(
+        sent,
+        [
+            keep_alive(0),
+            chunk_packet(0, 0),
+            keep_alive(1),
+            keep_alive(2),
+            block_update(-1)
+        ]
+    );
+
+    // Once the client catches up, deferred chunks go out in order
+    assert!(queue.send_deferred(&limits));
+    assert_eq!(receiver.try_recv().ok(), Some(chunk_packet(2, 0)));
+    assert_eq!(receiver.try_recv().ok(), Some(block_update(40)));
+    assert!(receiver.try_recv().is_err());
+
+    // A client that never catches up is disconnected once too many packets are deferred
+    for chunk_x in 0..5 {
+        assert!(queue.send(chunk_packet(chunk_x, 1), &limits));
+    }
+    assert!(send_queue.is_too_slow());
+
+    // Deferred chunks of the old world are never sent after a respawn
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(limits.capacity);
+    let send_queue: Arc<SendQueue> = Arc::new(SendQueue::default());
+    let mut queue: Queue = Queue::new(sender, send_queue.clone());
+    let respawn = || {
+        OutgoingPacket::Respawn(Box::new(RespawnData {
+            dimension_type: Dimension::Nether.type_name().to_string(),
+            dimension_name: "minecraft:the_nether".to_string(),
+            hashed_seed: 0,
+            gamemode: Gamemode::Creative,
+            previous_gamemode: None,
+            is_debug: false,
+            is_flat: false,
+            data_kept: 0x01,
+            death_location: None,
+            portal_cooldown: 0,
+        }))
+    };
+    for packet in [keep_alive(0), keep_alive(1), chunk_packet(0, 0), respawn()] {
+        assert!(queue.send(packet, &limits));
+    }
+    let mut sent: Vec<OutgoingPacket> = vec![];
+    while let Ok(packet) = receiver.try_recv() {
+        send_queue.pop();
+        sent.push(packet);
+    }
+    assert_eq!(sent, [keep_alive(0), keep_alive(1), respawn()]);
+    assert!(queue.send_deferred(&limits));
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn test_send_queue_disconnect() {
+    let limits: SendQueueLimits = SendQueueLimits {
+        capacity: 4,
+        high_water_mark: 2,
+        slow_clients: SlowClientPolicy::Disconnect,
+        max_deferred: 0,
+    };
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(limits.capacity);
+    let send_queue: Arc<SendQueue> = Arc::new(SendQueue::default());
+    let mut queue: Queue = Queue::new(sender, send_queue.clone());
+
+    // Nothing is deferred, the client is disconnected as soon as it falls behind
+    assert!(queue.send(chunk_packet(0, 0), &limits));
+    assert!(queue.send(chunk_packet(1, 0), &limits));
+    assert!(!send_queue.is_too_slow());
+    assert!(queue.send(chunk_packet(2, 0), &limits));
+    assert!(send_queue.is_too_slow());
+    for chunk_x in 0..3 {
+        assert_eq!(receiver.try_recv().ok(), Some(chunk_packet(chunk_x, 0)));
+    }
+
+    // The connection is closed once the writer is gone
+    drop(receiver);
+    assert!(!queue.send(chunk_packet(3, 0), &limits));
+}
+
+#[tokio::test]
+async fn test_proxy_protocol() -> Result<()> {
+    let client: SocketAddr = "192.168.0.1:56324".parse()?;
+
+    // v1, followed by the start of a handshake that must be left unread
+    let mut stream: &[u8] = b"PROXY TCP4 192.168.0.1 192.168.0.11 56324 25565\r\n\x10\x00";
+    assert_eq!(read_proxy_header(&mut stream).await?, Some(client));
+    assert_eq!(stream, b"\x10\x00");
+
+    let mut stream: &[u8] = b"PROXY UNKNOWN\r\n";
+    assert_eq!(read_proxy_header(&mut stream).await?, None);
+
+    let mut stream: &[u8] = b"PROXY TCP4 192.168.0.1\r\n";
+    assert!(read_proxy_header(&mut stream).await.is_err());
+
+    // v2 with a TLV after the addresses
+    let mut header: Vec<u8> = b"\r\n\r\n\0\r\nQUIT\n\x21\x11\x00\x0f".to_vec();
+    header.extend([192, 168, 0, 1, 192, 168, 0, 11]);
+    header.extend(56324u16.to_be_bytes());
+    header.extend(25565u16.to_be_bytes());
+    header.extend([0x04, 0x00, 0x00]);
+    header.extend(b"\x10\x00");
+    let mut stream: &[u8] = &header;
+    assert_eq!(read_proxy_header(&mut stream).await?, Some(client));
+    assert_eq!(stream, b"\x10\x00");
+
+    // v2 LOCAL
+    let mut stream: &[u8] = b"\r\n\r\n\0\r\nQUIT\n\x20\x00\x00\x00";
+    assert_eq!(read_proxy_header(&mut stream).await?, None);
+
+    // No header at all
+    let mut stream: &[u8] = b"\x10\x00";
+    assert!(read_proxy_header(&mut stream).await.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_bungeecord_forwarding() -> Result<()> {
+    let fields: [&str; 4] = [
+        "localhost",
+        "192.168.0.1",
+        "069a79f444e94726a5befca90e38aaf5",
+        r#"[{"name":"textures","value":"abc","signature":"def"}]"#,
+    ];
+
+    let forwarded = parse_bungeecord(&fields.join("\0"))?;
+    assert_eq!(forwarded.address, "192.168.0.1".parse::<IpAddr>()?);
+    assert_eq!(forwarded.uuid, Uuid(0x069a79f444e94726a5befca90e38aaf5));
+    assert_eq!(
+        forwarded.properties,
+        vec![(
+            "textures".to_string(),
+            "abc".to_string(),
+            Some("def".to_string())
+        )]
+    );
+
+    // Properties are optional
+    assert!(parse_bungeecord(&fields[..3].join("\0"))?
+        .properties
+        .is_empty());
+    assert!(parse_bungeecord("localhost").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_velocity_forwarding() -> Result<()> {
+    let mut payload: Vec<u8> = vec![];
+    payload.write_varint(1);
+    payload.write_string("192.168.0.1");
+    payload.extend_from_slice(&0x069a79f444e94726a5befca90e38aaf5u128.to_be_bytes());
+    payload.write_string("Notch");
+    payload.write_varint(1);
+    payload.write_string("textures");
+    payload.write_string("abc");
+    payload.push(0);
+
+    let mut mac: Hmac<Sha256> = Hmac::new_from_slice(b"secret")?;
+    mac.update(&payload);
+    let mut data: Vec<u8> = mac.finalize().into_bytes().to_vec();
+    data.extend_from_slice(&payload);
+
+    let (forwarded, name) = parse_velocity(&data, b"secret")?;
+    assert_eq!(name, "Notch");
+    assert_eq!(forwarded.address, "192.168.0.1".parse::<IpAddr>()?);
+    assert_eq!(forwarded.uuid, Uuid(0x069a79f444e94726a5befca90e38aaf5));
+    assert_eq!(
+        forwarded.properties,
+        vec![("textures".to_string(), "abc".to_string(), None)]
+    );
+
+    assert!(parse_velocity(&data, b"wrong secret").is_err());
+    data[40] ^= 1;
+    assert!(parse_velocity(&data, b"secret").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_entity_ids() {
+    let mut entity_ids: EntityIds = EntityIds::default();
+    assert_eq!(entity_ids.allocate(), 0);
+    assert_eq!(entity_ids.allocate(), 1);
+    assert_eq!(entity_ids.allocate(), 2);
+
+    // Freed IDs are reused, lowest first
+    entity_ids.free(2);
+    entity_ids.free(0);
+    assert_eq!(entity_ids.allocate(), 0);
+    assert_eq!(entity_ids.allocate(), 2);
+    assert_eq!(entity_ids.allocate(), 3);
+}
+
+#[test]
+fn test_spiral_chunks() {
+    assert_eq!(
+        spiral_chunks(3, -2, 1),
+        vec![
+            (3, -2),
+            (4, -2),
+            (4, -1),
+            (3, -1),
+            (2, -1),
+            (2, -2),
+            (2, -3),
+            (3, -3),
+            (4, -3),
+        ]
+    );
+
+    // Every chunk in view is returned once, closest first
+    let chunks: Vec<(i32, i32)> = spiral_chunks(0, 0, 16);
+    assert_eq!(chunks.len(), 33 * 33);
+    assert_eq!(chunks.iter().collect::<HashSet<_>>().len(), chunks.len());
+    assert!(chunks
+        .windows(2)
+        .all(|w| w[0].0.abs().max(w[0].1.abs()) <= w[1].0.abs().max(w[1].1.abs())));
+}
+
+#[test]
+fn test_protocol_mismatch() {
+    assert_eq!(protocol_mismatch(763), None);
+    // 1.19.4, 1.20.2 and clients that do not know their version
+    for protocol_version in [762, 764, -1] {
+        assert_eq!(
+            protocol_mismatch(protocol_version).as_deref(),
+            Some("Outdated client/server, please use 1.20.1")
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_recording() -> Result<()> {
+    let records: Vec<Record> = vec![
+        Record {
+            time: Duration::from_micros(0),
+            direction: Direction::Serverbound,
+            frame: vec![0x00, 0xFB, 0x05],
+        },
+        Record {
+            time: Duration::from_micros(1500),
+            direction: Direction::Clientbound,
+            frame: OutgoingPacket::SetCompression { threshold: 256 }.encode_frame(-1)?,
+        },
+    ];
+
+    let mut recording: Vec<u8> = RECORDING_MAGIC.to_vec();
+    recording.extend_from_slice(&763u16.to_be_bytes());
+    for record in &records {
+        recording.extend_from_slice(&record.to_bytes());
+    }
+
+    let mut reader: &[u8] = &recording;
+    assert_eq!(read_recording_header(&mut reader).await?, 763);
+    for record in records {
+        assert_eq!(Record::read(&mut reader).await?, Some(record));
+    }
+    assert_eq!(Record::read(&mut reader).await?, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_outgoing_packets() -> Result<()> {
+    let packets = || {
+        vec![
+            OutgoingPacket::KeepAlive { keep_alive_id: 42 },
+            OutgoingPacket::SetContainerSlot {
+                window_id: 0,
+                state_id: 3,
+                slot: 36,
+                slot_data: Some((1, 64, nbt!({ "Damage": 1 }))),
+            },
+            OutgoingPacket::PlayerInfoUpdate {
+                actions: 0x01 | 0x08,
+                players: vec![(
+                    Uuid(7),
+                    vec![
+                        PlayerInfoUpdateActions::AddPlayer {
+                            name: "Steve".to_string(),
+                            properties: vec![("textures".to_string(), "{}".to_string(), None)],
+                        },
+                        PlayerInfoUpdateActions::UpdateListed { listed: true },
+                    ],
+                )],
+            },
+            OutgoingPacket::UpdateEntityRotation {
+                entity_id: 5,
+                yaw: 90.0,
+                pitch: 45.0,
+                on_ground: true,
+            },
+            OutgoingPacket::SetEntityMetadata {
+                entity_id: 5,
+                metadata: EntityMetadata(vec![(0, EntityMetadataField::Byte(0x02))]),
+            },
+            OutgoingPacket::Respawn(Box::new(RespawnData {
+                dimension_type: "minecraft:overworld".to_string(),
+                dimension_name: "minecraft:overworld".to_string(),
+                hashed_seed: 0,
+                gamemode: Gamemode::Survival,
+                previous_gamemode: None,
+                is_debug: false,
+                is_flat: true,
+                data_kept: 0x01,
+                death_location: None,
+                portal_cooldown: 0,
+            })),
+        ]
+    };
+
+    for threshold in [-1, 0] {
+        for (packet, expected) in packets().into_iter().zip(packets()) {
+            let frame: Vec<u8> = packet.encode_frame(threshold)?;
+            assert_eq!(
+                OutgoingPacket::decode(frame, &ConnectionState::Play, threshold)?,
+                expected
+            );
+        }
+    }
+    assert!(OutgoingPacket::decode(vec![0x7F], &ConnectionState::Play, -1).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_block_properties() -> Result<()> {
+    let door: Block = Block::OakDoor {
+        half: DoubleBlockHalf::Upper,
+        facing: HorizontalFacing::East,
+        open: true,
+        hinge: DoorHinge::Left,
+        powered: false,
+    };
+    assert_eq!(door.name(), "oak_door");
+    let properties: HashMap<String, String> = door
+        .properties()
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    assert_eq!(properties["half"], "upper");
+    assert_eq!(properties["hinge"], "left");
+    assert_eq!(
+        Block::from_properties("minecraft:oak_door", &properties)?,
+        door
+    );
+
+    let stairs: HashMap<String, String> = HashMap::from(
+        [
+            ("facing", "north"),
+            ("half", "top"),
+            ("shape", "outer_left"),
+            ("waterlogged", "false"),
+        ]
+        .map(|(key, value)| (key.to_string(), value.to_string())),
+    );
+    assert_eq!(
+        Block::from_properties("oak_stairs", &stairs)?,
+        Block::OakStairs {
+            facing: HorizontalFacing::North,
+            half: Half::Top,
+            shape: StairsShape::OuterLeft,
+            waterlogged: false,
+        }
+    );
+    assert_eq!(
+        Block::from_properties("oak_stairs", &HashMap::new())?,
+        Block::from_name("oak_stairs")?
+    );
+    assert!(Block::from_properties("minecraft:not_a_block", &HashMap::new()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_region_file() -> Result<()> {
+    let mut chunk: Chunk = Chunk::new(
+        Dimension::Overworld,
+        vec![ChunkSection::new(vec![Block::Air; 4096], Biome::Plains); 24],
+    );
+    chunk.chunk_sections[0] = ChunkSection::new(vec![Block::Bedrock; 256], Biome::Plains);
+    chunk.chunk_sections[1] = ChunkSection::new(vec![Block::Stone; 4096], Biome::Plains);
+    // More than 16 states, so the section needs more than 4 bits per block
+    for i in 0..4096usize {
+        let block: Block = Block::RedstoneWire {
+            north: RedstoneWireConnection::Side,
+            east: RedstoneWireConnection::None,
+            south: RedstoneWireConnection::Up,
+            west: RedstoneWireConnection::None,
+            power: I0_15::new((i % 16) as u8).unwrap(),
+        };
+        let (x, y, z): (u8, u8, u8) = ((i % 16) as u8, (i / 256) as u8, (i / 16 % 16) as u8);
+        chunk.chunk_sections[4].set_block(x, y, z, block);
+    }
+    chunk.chunk_sections[4].set_block(15, 15, 15, Block::GrassBlock { snowy: true });
+    chunk.chunk_sections[4].biomes[..16].fill(Biome::Desert);
+    chunk.chunk_sections[4].biomes[63] = Biome::SnowyPlains;
+    chunk.chunk_sections[23].biomes.fill(Biome::TheVoid);
+    chunk.heightmaps = Heightmaps::new(&chunk);
+
+    let mut region: RegionFile<Cursor<Vec<u8>>> = RegionFile::new(Cursor::new(vec![]))?;
+    assert_eq!(region.read_chunk(1, -1)?, None);
+    region.write_chunk(1, -1, &serialize_chunk(33, -1, &chunk)?)?;
+    let nbt: Vec<u8> = region.read_chunk(33, 31)?.unwrap();
+    assert_eq!(deserialize_chunk(&nbt, Dimension::Overworld)?, chunk);
+
+    // Overwriting a chunk moves it to new sectors
+    chunk.chunk_sections[1] = ChunkSection::new(vec![Block::Dirt; 4096], Biome::Plains);
+    assert!(chunk.set_block(15, 319, 15, Block::Stone));
+    region.write_chunk(1, -1, &serialize_chunk(33, -1, &chunk)?)?;
+    let nbt: Vec<u8> = region.read_chunk(1, -1)?.unwrap();
+    assert_eq!(deserialize_chunk(&nbt, Dimension::Overworld)?, chunk);
+
+    Ok(())
+}
+
+#[test]
+fn test_block_state_ids() -> Result<()> {
+    let reference: HashMap<String, serde_json::Value> =
+        serde_json::from_str(include_str!("../ref/1.20.1_blocks.json"))?;
+
+    let mut state_count: u16 = 0;
+    for (name, block_info) in reference {
+        let min_state_id: u16 = block_info["min_state_id"].as_u64().unwrap() as u16;
+        let num_states: u16 = block_info["num_states"].as_u64().unwrap() as u16;
+        state_count += num_states;
+
+        for id in min_state_id..min_state_id + num_states {
+            let block: Block = Block::from_state_id(id).unwrap();
+            assert_eq!(block.name(), name, "{id}");
+            assert_eq!(
+                block.info().display_name,
+                block_info["display_name"].as_str()
+            );
+            assert_eq!(block.get_state_id(), id, "{block:?}");
+            assert_eq!(block.to_string().parse::<Block>()?, block);
+        }
+    }
+    assert_eq!(Block::from_state_id(state_count), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_block_display() -> Result<()> {
+    let stairs: Block = Block::OakStairs {
+        facing: HorizontalFacing::East,
+        half: Half::Top,
+        shape: StairsShape::Straight,
+        waterlogged: false,
+    };
+    assert_eq!(
+        stairs.to_string(),
+        "minecraft:oak_stairs[facing=east,half=top,shape=straight,waterlogged=false]"
+    );
+    assert_eq!(
+        "oak_stairs[waterlogged=false, shape=straight, half=top, facing=east]".parse::<Block>()?,
+        stairs
+    );
+    assert_eq!(Block::Stone.to_string(), "minecraft:stone");
+    assert_eq!("minecraft:stone".parse::<Block>()?, Block::Stone);
+    // Omitted properties take the value of the default state
+    assert_eq!(
+        "minecraft:oak_stairs[facing=east,half=top]"
+            .parse::<Block>()?
+            .get_state_id(),
+        2935
+    );
+    assert_eq!(
+        "minecraft:grass_block".parse::<Block>()?,
+        Block::GrassBlock { snowy: false }
+    );
+    assert!("minecraft:oak_stairs[facing=up]".parse::<Block>().is_err());
+    assert!("minecraft:oak_stairs[facing=east".parse::<Block>().is_err());
+    assert!("minecraft:oak_stairs[facing]".parse::<Block>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_biomes() -> Result<()> {
+    // Ids are the order of the registry codec
+    assert_eq!(Biome::Badlands.id(), 0);
+    assert_eq!(Biome::Plains.id(), 39);
+    assert_eq!(Biome::TheVoid.id(), 56);
+    assert_eq!(Biome::WoodedBadlands.id(), 63);
+    assert_eq!(Biome::from_id(64), None);
+    for id in 0..64 {
+        let biome: Biome = Biome::from_id(id).unwrap();
+        assert_eq!(biome.id(), id);
+        assert_eq!(biome.to_string().parse::<Biome>()?, biome);
+    }
+    assert_eq!("snowy_plains".parse::<Biome>()?, Biome::SnowyPlains);
+    assert_eq!(Biome::SnowyPlains.info().temperature, 0.0);
+    assert!(!Biome::Desert.info().has_precipitation);
+    assert!("minecraft:not_a_biome".parse::<Biome>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_chunk_section_biomes() {
+    let biomes = |section: &ChunkSection| {
+        let bytes: Vec<u8> = section.to_bytes();
+        // The blocks are a single value palette of air
+        bytes[5..].to_vec()
+    };
+
+    let mut section: ChunkSection = ChunkSection::new(vec![], Biome::Desert);
+    assert_eq!(biomes(&section), vec![0, 14, 0]);
+
+    // Indirect palettes are sorted by id
+    section.biomes[1] = Biome::Badlands;
+    let mut expected: Vec<u8> = vec![1, 2, 0, 14, 1];
+    expected.extend_from_slice(&(!0b10_i64).to_be_bytes());
+    assert_eq!(biomes(&section), expected);
+    assert_eq!(section.get_biome(5, 3, 2), Some(&Biome::Badlands));
+    assert_eq!(section.get_biome(4, 4, 0), Some(&Biome::Desert));
+
+    // More than 8 biomes are sent as ids, 10 per long
+    for (i, biome) in section.biomes.iter_mut().enumerate() {
+        *biome = Biome::from_id((i % 9) as u8).unwrap();
+    }
+    let bytes: Vec<u8> = biomes(&section);
+    assert_eq!(&bytes[..2], &[6, 7]);
+    assert_eq!(bytes.len(), 2 + 7 * 8);
+    assert_eq!(
+        i64::from_be_bytes(bytes[2..10].try_into().unwrap()) & 0x3F,
+        0
+    );
+    assert_eq!(
+        i64::from_be_bytes(bytes[2..10].try_into().unwrap()) >> 6 & 0x3F,
+        1
+    );
+}
+
+#[test]
+fn test_world_generators() {
+    let flat: Chunk = FlatGenerator::default().generate_chunk(5, -7, Dimension::Overworld);
+    assert_eq!(flat.get_block(3, -64, 9), Some(Block::Bedrock));
+    assert_eq!(flat.get_block(3, 0, 9), Some(Block::Stone));
+    assert_eq!(
+        flat.get_block(3, 64, 9),
+        Some(Block::GrassBlock { snowy: false })
+    );
+    assert_eq!(flat.max_height_at(3, 9), Some(64));
+    assert_eq!(flat.get_biome(3, 64, 9), Some(&Biome::Plains));
+
+    // Separately seeded noise with the same seed gives the same terrain
+    let generator: NoiseGenerator = NoiseGenerator::new(seeded_noise(SEED));
+    let same_seed: NoiseGenerator = NoiseGenerator::new(seeded_noise(SEED));
+    let other_seed: NoiseGenerator = NoiseGenerator::new(seeded_noise(SEED + 1));
+    for (chunk_x, chunk_z) in [(0, 0), (-3, 12), (40, -25)] {
+        let chunk: Chunk = generator.generate_chunk(chunk_x, chunk_z, Dimension::Overworld);
+        assert_eq!(
+            chunk,
+            same_seed.generate_chunk(chunk_x, chunk_z, Dimension::Overworld)
+        );
+        assert_ne!(
+            chunk,
+            other_seed.generate_chunk(chunk_x, chunk_z, Dimension::Overworld)
+        );
+
+        for (x, z) in [(0, 0), (7, 12), (15, 15)] {
+            let height: i32 = generator.height_at(chunk_x * 16 + x as i32, chunk_z * 16 + z as i32);
+            assert_eq!(chunk.get_block(x, -64, z), Some(Block::Bedrock));
+            assert_eq!(chunk.get_block(x, height - 4, z), Some(Block::Stone));
+            assert!(matches!(
+                chunk.get_block(x, height - 1, z),
+                Some(Block::Dirt | Block::Sand)
+            ));
+            for y in height + 1..SEA_LEVEL {
+                assert!(matches!(
+                    chunk.get_block(x, y, z),
+                    Some(Block::Water { .. })
+                ));
+            }
+            let biome: Biome = generator.biome_at(chunk_x * 16 + x as i32, chunk_z * 16 + z as i32);
+            if height + 1 < SEA_LEVEL {
+                assert!(biome.name().ends_with("ocean"));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_heightmaps() {
+    let mut chunk: Chunk = FlatGenerator::default().generate_chunk(0, 0, Dimension::Overworld);
+    // Heights are counted from the bottom of the world, above the highest block
+    assert_eq!(chunk.heightmaps.world_surface, vec![129; 256]);
+    assert_eq!(chunk.heightmaps.motion_blocking, vec![129; 256]);
+
+    assert!(chunk.set_block(3, 100, 4, Block::Stone));
+    assert!(chunk.set_block(5, 65, 6, Block::Torch));
+    assert!(!chunk.set_block(0, 320, 0, Block::Stone));
+    assert!(!chunk.set_block(0, -65, 0, Block::Stone));
+    assert_eq!(chunk.heightmaps.world_surface[3 + 4 * 16], 165);
+    assert_eq!(chunk.heightmaps.motion_blocking[3 + 4 * 16], 165);
+    assert_eq!(chunk.heightmaps.world_surface[5 + 6 * 16], 130);
+    assert_eq!(chunk.heightmaps.motion_blocking[5 + 6 * 16], 129);
+
+    // Removing the highest block finds the next one
+    assert!(chunk.set_block(3, 100, 4, Block::Air));
+    assert!(chunk.set_block(7, 64, 7, Block::Air));
+    assert_eq!(chunk.heightmaps.world_surface[3 + 4 * 16], 129);
+    assert_eq!(chunk.heightmaps.world_surface[7 + 7 * 16], 128);
+    assert_eq!(chunk.heightmaps, Heightmaps::new(&chunk));
+
+    // 9 bits per height and 7 heights per long
+    let fastnbt::Value::Compound(nbt) = chunk.heightmaps.to_nbt(&chunk) else {
+        panic!("Heightmaps are not a compound");
+    };
+    let Some(fastnbt::Value::LongArray(world_surface)) = nbt.get("WORLD_SURFACE") else {
+        panic!("Missing WORLD_SURFACE");
+    };
+    assert_eq!(world_surface.len(), 37);
+    assert_eq!(
+        world_surface[0],
+        129 | 129 << 9 | 129 << 18 | 129 << 27 | 129 << 36 | 129 << 45 | 129 << 54
+    );
+}
+
+#[test]
+fn test_flat_presets() -> Result<()> {
+    let generator: FlatGenerator = FlatGenerator::new(
+        "minecraft:bedrock, 2*minecraft:dirt,oak_log,grass_block",
+        Biome::Desert,
+    )?;
+    let chunk: Chunk = generator.generate_chunk(3, -7, Dimension::Nether);
+    assert_eq!(chunk.get_block(0, 0, 0), Some(Block::Bedrock));
+    assert_eq!(chunk.get_block(5, 2, 9), Some(Block::Dirt));
+    assert_eq!(
+        chunk.get_block(5, 3, 9),
+        Some(Block::OakLog { axis: Axis::Y })
+    );
+    assert_eq!(
+        chunk.get_block(15, 4, 15),
+        Some(Block::GrassBlock { snowy: false })
+    );
+    assert_eq!(chunk.get_block(15, 5, 15), Some(Block::Air));
+    assert_eq!(chunk.chunk_sections[0].biomes[0], Biome::Desert);
+
+    // The default layers put grass at y=64 in the overworld
+    let chunk: Chunk = FlatGenerator::default().generate_chunk(0, 0, Dimension::Overworld);
+    assert_eq!(chunk.get_block(0, -64, 0), Some(Block::Bedrock));
+    assert_eq!(chunk.get_block(0, 59, 0), Some(Block::Stone));
+    assert_eq!(chunk.get_block(0, 60, 0), Some(Block::Dirt));
+    assert_eq!(
+        chunk.get_block(0, 64, 0),
+        Some(Block::GrassBlock { snowy: false })
+    );
+
+    assert!(FlatGenerator::new("", Biome::Plains).is_ok());
+    assert!(FlatGenerator::new("2*minecraft:not_a_block", Biome::Plains).is_err());
+    assert!(FlatGenerator::new("x*minecraft:stone", Biome::Plains).is_err());
+    assert!(FlatGenerator::new("5000*minecraft:stone", Biome::Plains).is_err());
+    Ok(())
+}
+
+/// Lets the workers of a world run until `done` is true.
+async fn wait_for(world: &mut World, done: impl Fn(&mut World) -> bool) {
+    for _ in 0..1000 {
+        world.receive_chunks();
+        if done(world) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("The workers of {} did not finish", world.name());
+}
+
+#[tokio::test]
+async fn test_light() {
+    let mut world: World = World::new(
+        "minecraft:overworld",
+        Dimension::Overworld,
+        Box::new(FlatGenerator::default()),
+    );
+    wait_for(&mut world, |world| world.get_chunk_data(0, 0).is_some()).await;
+    let light = |chunk: &Chunk, x: usize, y: i32, z: usize| {
+        let i: usize = x + z * 16 + (y + 64).rem_euclid(16) as usize * 256;
+        let section = &chunk.chunk_sections[(y + 64) as usize / 16];
+        (
+            section.sky_light[i / 2] >> (i % 2 * 4) & 0xF,
+            section.block_light[i / 2] >> (i % 2 * 4) & 0xF,
+        )
+    };
+    let chunk: Chunk = world.get_chunk(0, 0).unwrap().clone();
+    assert_eq!(light(&chunk, 3, 65, 3), (15, 0));
+    assert_eq!(light(&chunk, 3, 64, 3), (0, 0));
+
+    // Every change is relit like the whole chunk would be
+    let changes: [(i32, i32, i32, Block); 7] = [
+        (5, 65, 5, Block::Torch),
+        (5, 70, 5, Block::Glowstone),
+        (4, 66, 5, Block::Stone),
+        (5, 64, 5, Block::Air),
+        (5, 63, 5, Block::Air),
+        (5, 65, 5, Block::Air),
+        (15, 66, 0, Block::Stone),
+    ];
+    for (x, y, z, block) in changes {
+        assert!(world.set_block(x, y, z, block));
+        let chunk: Chunk = world.get_chunk(0, 0).unwrap().clone();
+        let mut relit: Chunk = chunk.clone();
+        light_chunk(&mut relit);
+        assert_eq!(chunk, relit, "{block} at {x} {y} {z} was not relit");
+    }
+    let chunk: Chunk = world.get_chunk(0, 0).unwrap().clone();
+    assert_eq!(light(&chunk, 5, 70, 5), (0, 15));
+    assert_eq!(light(&chunk, 5, 71, 5), (15, 14));
+    assert_eq!(light(&chunk, 5, 69, 5).1, 14);
+    // The glowstone keeps the sky from falling straight down the hole
+    assert_eq!(light(&chunk, 5, 63, 5), (12, 8));
+
+    // Light spreads into the chunks around, and is taken out of them again
+    assert!(world.set_block(15, 70, 15, Block::Glowstone));
+    let neighbour: &Chunk = world.get_chunk(1, 0).unwrap();
+    assert_eq!(light(neighbour, 0, 70, 15), (15, 14));
+    assert_eq!(light(neighbour, 1, 70, 15), (15, 13));
+    assert!(world.set_block(15, 70, 15, Block::Stone));
+    assert_eq!(light(world.get_chunk(1, 0).unwrap(), 0, 70, 15), (15, 0));
+    // Chunks that are loaded later are lit by the chunks around them
+    assert!(world.set_block(-16, 70, 8, Block::Glowstone));
+    assert!(world.get_chunk(-2, 0).is_none());
+    wait_for(&mut world, |world| world.get_chunk_data(-2, 0).is_some()).await;
+    assert_eq!(light(world.get_chunk(-2, 0).unwrap(), 15, 70, 8), (15, 14));
+
+    // Sections without light are empty, and the section above the world is fully lit
+    let light_data: LightData = LightData::new(&chunk);
+    assert_eq!(light_data.sky_light_mask, vec![0x3FFFF << 8]);
+    assert_eq!(light_data.empty_sky_light_mask, vec![0xFF]);
+    assert_eq!(light_data.sky_light_arrays.len(), 18);
+    assert_eq!(light_data.sky_light_arrays[17], [0xFF; 2048]);
+    assert_eq!(light_data.block_light_mask, vec![0b111 << 8]);
+    assert_eq!(
+        light_data.empty_block_light_mask,
+        vec![0x3FFFFFF & !(0b111 << 8)]
+    );
+    assert_eq!(light_data.block_light_arrays.len(), 3);
+}
+
+#[tokio::test]
+async fn test_chunk_workers() -> Result<()> {
+    // Heightmaps are compounds, so their tags can be written in any order
+    let same_data = |data: &[u8], chunk: &Chunk| -> Result<bool> {
+        let expected: Vec<u8> = encode_chunk_data(chunk)?;
+        let length: usize = fastnbt::to_bytes(&chunk.heightmaps.to_nbt(chunk))?.len();
+        Ok(fastnbt::from_bytes::<fastnbt::Value>(&data[..length])?
+            == fastnbt::from_bytes::<fastnbt::Value>(&expected[..length])?
+            && data[length..] == expected[length..])
+    };
+    let mut world: World = World::new(
+        "minecraft:overworld",
+        Dimension::Overworld,
+        Box::new(NoiseGenerator::default()),
+    );
+
+    // Chunks are queued until a worker finished them and the chunks around them, then cached
+    assert_eq!(world.get_chunk_data(1, -2), None);
+    assert_eq!(world.get_chunk_data(1, -2), None);
+    let mut received: Vec<(i32, i32)> = vec![];
+    let mut data: Option<Arc<Vec<u8>>> = None;
+    for _ in 0..500 {
+        received.extend(world.receive_chunks());
+        data = world.get_chunk_data(1, -2);
+        if data.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    received.sort();
+    let mut expected: Vec<(i32, i32)> = (0..3)
+        .flat_map(|x| (-3..0).map(move |z| (x, z)))
+        .chain([(1, -2)])
+        .collect();
+    expected.sort();
+    assert_eq!(received, expected);
+    let data: Arc<Vec<u8>> = data.unwrap();
+    assert!(same_data(&data, world.get_chunk(1, -2).unwrap())?);
+    assert!(Arc::ptr_eq(&data, &world.get_chunk_data(1, -2).unwrap()));
+
+    // Changed chunks are encoded again
+    assert!(world.set_block(16, 100, -32, Block::Glowstone));
+    assert_eq!(world.get_chunk_data(1, -2), None);
+    wait_for(&mut world, |world| world.get_chunk_data(1, -2).is_some()).await;
+    let data: Arc<Vec<u8>> = world.get_chunk_data(1, -2).unwrap();
+    let chunk: &Chunk = world.get_chunk(1, -2).unwrap();
+    assert_eq!(chunk.get_block(0, 100, 0), Some(Block::Glowstone));
+    assert!(same_data(&data, chunk)?);
+
+    // Blocks of chunks that are not loaded are changed by the workers that load them
+    assert!(world.set_block(100, 100, 100, Block::Glowstone));
+    assert_eq!(world.get_block(100, 100, 100), None);
+    wait_for(&mut world, |world| world.get_block(100, 100, 100).is_some()).await;
+    assert_eq!(world.get_block(100, 100, 100), Some(Block::Glowstone));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chunk_unloading() -> Result<()> {
+    let directory: PathBuf = std::env::temp_dir().join(format!("world-{}", std::process::id()));
+    let mut world: World = World::open(
+        &directory,
+        "minecraft:overworld",
+        Dimension::Overworld,
+        Box::new(FlatGenerator::default()),
+    )?;
+    assert!(world.set_block(3, 70, 3, Block::Glowstone));
+    wait_for(&mut world, |world| world.get_block(3, 70, 3).is_some()).await;
+    wait_for(&mut world, |world| world.get_chunk_data(3, 0).is_some()).await;
+    world.add_viewer(3, 0);
+
+    // Unused chunks are dropped once workers saved them if they changed,
+    // viewed chunks and the chunks lighting them are kept
+    assert_eq!(world.unload_chunks(Duration::ZERO), 0);
+    assert_eq!(world.loaded_chunk_count(), 10);
+    wait_for(&mut world, |world| world.unload_chunks(Duration::ZERO) == 1).await;
+    assert_eq!(world.loaded_chunk_count(), 9);
+    let mut reopened: World = World::open(
+        &directory,
+        "minecraft:overworld",
+        Dimension::Overworld,
+        Box::new(FlatGenerator::default()),
+    )?;
+    wait_for(&mut reopened, |world| world.get_chunk_data(0, 0).is_some()).await;
+    assert_eq!(reopened.get_block(3, 70, 3), Some(Block::Glowstone));
+
+    world.remove_viewer(3, 0);
+    assert_eq!(world.unload_chunks(Duration::from_secs(60)), 0);
+    assert_eq!(world.unload_chunks(Duration::ZERO), 9);
+    assert_eq!(world.loaded_chunk_count(), 0);
+
+    // Chunks that failed to save are kept until they are saved
+    let region: PathBuf = directory.join("region/r.1.0.mca");
+    fs::create_dir(&region)?;
+    assert!(world.set_block(640, 70, 0, Block::Glowstone));
+    wait_for(&mut world, |world| world.get_block(640, 70, 0).is_some()).await;
+    for _ in 0..20 {
+        world.unload_chunks(Duration::ZERO);
+        world.receive_chunks();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(world.loaded_chunk_count(), 1);
+    fs::remove_dir(&region)?;
+    wait_for(&mut world, |world| world.unload_chunks(Duration::ZERO) == 1).await;
+
+    // Chunks that changed are never dropped without storage
+    let mut memory: World = World::new(
+        "minecraft:overworld",
+        Dimension::Overworld,
+        Box::new(FlatGenerator::default()),
+    );
+    assert!(memory.set_block(3, 70, 3, Block::Glowstone));
+    wait_for(&mut memory, |world| world.get_block(3, 70, 3).is_some()).await;
+    memory.save_chunks();
+    assert_eq!(memory.unload_chunks(Duration::ZERO), 0);
+    assert_eq!(memory.get_block(3, 70, 3), Some(Block::Glowstone));
+
+    fs::remove_dir_all(directory)?;
+    Ok(())
+}
+
+#[test]
+fn test_block_packets() -> Result<()> {
+    let location: Position = Position {
+        x: -33554432,
+        y: -64,
+        z: 33554431,
+    };
+    assert_eq!(
+        Position::from_i64(i64::from_be_bytes(location.to_bytes())),
+        location
+    );
+
+    // Player Action: finished digging the top of a block
+    let mut frame: Vec<u8> = vec![];
+    frame.write_varint(0x1D);
+    frame.write_varint(2);
+    frame.extend_from_slice(&location.to_bytes());
+    frame.push(1);
+    frame.write_varint(7);
+    match IncomingPacket::decode(frame, &ConnectionState::Play, -1)? {
+        IncomingPacket::PlayerAction {
+            status,
+            location: l,
+            face,
+            sequence,
+        } => {
+            assert_eq!(status, DiggingStatus::Finished);
+            assert_eq!(l, location);
+            assert_eq!(face, BlockFace::Top);
+            assert_eq!(sequence, 7);
+        }
+        p => panic!("Unexpected packet {p}"),
+    }
+
+    // Use Item On: the off hand against the east of a block
+    let mut frame: Vec<u8> = vec![];
+    frame.write_varint(0x31);
+    frame.write_varint(1);
+    frame.extend_from_slice(&location.to_bytes());
+    frame.write_varint(5);
+    for cursor in [1.0f32, 0.5, 0.25] {
+        frame.extend_from_slice(&cursor.to_be_bytes());
+    }
+    frame.push(0);
+    frame.write_varint(8);
+    match IncomingPacket::decode(frame, &ConnectionState::Play, -1)? {
+        IncomingPacket::UseItemOn {
+            hand,
+            face,
+            cursor_y,
+            inside_block,
+            sequence,
+            ..
+        } => {
+            assert_eq!((hand, face, cursor_y), (1, BlockFace::East, 0.5));
+            assert!(!inside_block);
+            assert_eq!(sequence, 8);
+        }
+        p => panic!("Unexpected packet {p}"),
+    }
+
+    let mut expected: Vec<u8> = vec![0x0A];
+    expected.extend_from_slice(&location.to_bytes());
+    expected.write_varint(1);
+    assert_eq!(
+        OutgoingPacket::BlockUpdate {
+            location,
+            block_id: 1
+        }
+        .encode_frame(-1)?,
+        expected
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dimension_packets() -> Result<()> {
+    // Chat Command: the argument signatures after the command are ignored
+    let mut frame: Vec<u8> = vec![];
+    frame.write_varint(0x04);
+    frame.write_string("world the_nether");
+    frame.extend_from_slice(&[0; 8 + 8 + 1 + 1 + 3]);
+    match IncomingPacket::decode(frame, &ConnectionState::Play, -1)? {
+        IncomingPacket::ChatCommand { command } => assert_eq!(command, "world the_nether"),
+        p => panic!("Unexpected packet {p}"),
+    }
+
+    let mut expected: Vec<u8> = vec![0x41];
+    expected.write_string("minecraft:the_nether");
+    expected.write_string("arena:lava");
+    expected.extend_from_slice(&5i64.to_be_bytes());
+    expected.extend_from_slice(&[1, 255, 0, 1, 0x01, 0]);
+    expected.write_varint(0);
+    assert_eq!(
+        OutgoingPacket::Respawn(Box::new(RespawnData {
+            dimension_type: Dimension::Nether.type_name().to_string(),
+            dimension_name: "arena:lava".to_string(),
+            hashed_seed: 5,
+            gamemode: Gamemode::Creative,
+            previous_gamemode: None,
+            is_debug: false,
+            is_flat: true,
+            data_kept: 0x01,
+            death_location: None,
+            portal_cooldown: 0,
+        }))
+        .encode_frame(-1)?,
+        expected
+    );
+
+    // Worlds that are not vanilla dimensions are saved under their namespace
+    let directory: PathBuf = std::env::temp_dir().join(format!("worlds-{}", std::process::id()));
+    let mut world: World = World::open(
+        &directory,
+        "arena:lava",
+        Dimension::Nether,
+        Box::new(FlatGenerator::default()),
+    )?;
+    wait_for(&mut world, |world| world.get_chunk_data(0, 0).is_some()).await;
+    assert_eq!(world.get_block(0, 0, 0), Some(Block::Bedrock));
+    assert_eq!(world.get_block(0, -1, 0), None);
+    assert!(world.is_flat());
+    assert!(directory
+        .join("dimensions/arena/lava/region/r.0.0.mca")
+        .exists());
+
+    fs::remove_dir_all(directory)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_schematics() -> Result<()> {
+    // A 2x2x1 Sponge schematic, indexed by y, then z, then x, with a block from a newer version
+    let palette = |version: i32| {
+        nbt!({
+            "minecraft:air": 0,
+            "minecraft:oak_log[axis=x]": 1,
+            "minecraft:stone": 2,
+            "minecraft:not_a_block": version,
+        })
+    };
+    let v2: Vec<u8> = fastnbt::to_bytes(&nbt!({
+        "Version": 2,
+        "DataVersion": 3465,
+        "Width": 2i16,
+        "Height": 2i16,
+        "Length": 1i16,
+        "Palette": palette(3),
+        "BlockData": [B; 1, 0, 2, 3],
+    }))?;
+    let v3: Vec<u8> = fastnbt::to_bytes(&nbt!({
+        "Schematic": {
+            "Version": 3,
+            "DataVersion": 3465,
+            "Width": 2i16,
+            "Height": 2i16,
+            "Length": 1i16,
+            "Blocks": {
+                "Palette": palette(3),
+                "Data": [B; 1, 0, 2, 3],
+            },
+        },
+    }))?;
+    let expected: Vec<((i32, i32, i32), Block)> = vec![
+        ((0, 0, 0), Block::OakLog { axis: Axis::X }),
+        ((1, 0, 0), Block::Air),
+        ((0, 1, 0), Block::Stone),
+    ];
+    for nbt in [v2, v3] {
+        let schematic: Schematic = Schematic::from_sponge(&nbt)?;
+        assert_eq!(schematic.size, (2, 2, 1));
+        assert_eq!(schematic.blocks, expected);
+    }
+    // Sizes are unsigned, and have to match the block data
+    for (size, data) in [(-1i16, vec![0i8; 4]), (2, vec![0; 3]), (2, vec![0; 5])] {
+        let nbt: Vec<u8> = fastnbt::to_bytes(&nbt!({
+            "Version": 2,
+            "DataVersion": 3465,
+            "Width": size,
+            "Height": size,
+            "Length": 1i16,
+            "Palette": palette(3),
+            "BlockData": fastnbt::ByteArray::new(data),
+        }))?;
+        assert!(Schematic::from_sponge(&nbt).is_err());
+    }
+
+    // Structures only list the blocks they change
+    let structure: Vec<u8> = fastnbt::to_bytes(&nbt!({
+        "DataVersion": 3465,
+        "size": [3, 1, 3],
+        "palette": [
+            {"Name": "minecraft:glowstone"},
+            {"Name": "minecraft:oak_log", "Properties": {"axis": "y"}},
+        ],
+        "blocks": [
+            {"state": 0, "pos": [0, 0, 0]},
+            {"state": 1, "pos": [2, 0, 2], "nbt": {}},
+        ],
+        "entities": [],
+    }))?;
+    let schematic: Schematic = Schematic::from_structure(&structure)?;
+    assert_eq!(schematic.size, (3, 1, 3));
+
+    let mut world: World = World::new(
+        "minecraft:overworld",
+        Dimension::Overworld,
+        Box::new(FlatGenerator::default()),
+    );
+    wait_for(&mut world, |world| world.get_chunk_data(0, 0).is_some()).await;
+    let mut changed: Vec<(i32, i32)> = world.paste(&schematic, (-1, 70, 14));
+    changed.sort();
+    assert_eq!(changed, vec![(-1, 0), (0, 1)]);
+    // Blocks are changed by workers
+    assert_eq!(world.get_block(-1, 70, 14), Some(Block::Air));
+    assert!(world.take_changed().is_empty());
+    wait_for(&mut world, |world| {
+        world.get_block(-1, 70, 14) == Some(Block::Glowstone)
+            && world.get_block(1, 70, 16) == Some(Block::OakLog { axis: Axis::Y })
+    })
+    .await;
+    assert_eq!(world.get_block(0, 70, 15), Some(Block::Air));
+    // The glowstone lights the chunk next to it, which has to be sent again
+    assert!(world.take_changed().contains(&(0, 0)));
+    // Pasted chunks are kept without storage
+    world.unload_chunks(Duration::ZERO);
+    assert_eq!(world.get_block(-1, 70, 14), Some(Block::Glowstone));
+
+    // Pasted chunks are relit
+    let chunk: Chunk = world.get_chunk(-1, 0).unwrap().clone();
+    let mut relit: Chunk = chunk.clone();
+    light_chunk(&mut relit);
+    assert_eq!(chunk, relit);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_placing_blocks() {
+    let block = |id: i32| ItemInfo::from_id(id).and_then(ItemInfo::block);
+    assert_eq!(block(1), Some(Block::Stone));
+    assert_eq!(block(110), Some(Block::OakLog { axis: Axis::Y }));
+    assert_eq!(
+        block(230),
+        Some(Block::OakSlab {
+            r#type: SlabType::Bottom,
+            waterlogged: false
+        })
+    );
+    // Sticks are not blocks
+    assert_eq!(block(807), None);
+    assert_eq!(block(-1), None);
+
+    // Survival players break blocks no faster than their tools allow
+    let speed = |id: i32| ItemInfo::from_id(id).unwrap().mining_speed();
+    assert_eq!(speed(807), 1.0);
+    assert_eq!(speed(799), 8.0);
+    assert_eq!(break_time(1.5, speed(807)), Duration::from_millis(2250));
+    assert_eq!(break_time(1.5, speed(799)), Duration::from_millis(300));
+    assert_eq!(break_time(0.0, 1.0), Duration::ZERO);
+    assert_eq!(break_time(0.4, speed(789)), Duration::ZERO);
+    // Like vanilla, the last 30% of the time are let go for lag: stone takes 6 ticks
+    // with a diamond pickaxe, and 5 are enough
+    let earliest: Duration =
+        break_time(Block::Stone.hardness().unwrap(), speed(799)).mul_f32(BREAK_TIME_TOLERANCE);
+    assert!(Duration::from_millis(200) < earliest && earliest <= Duration::from_millis(250));
+
+    let mut world: World = World::new(
+        "minecraft:overworld",
+        Dimension::Overworld,
+        Box::new(FlatGenerator::default()),
+    );
+    wait_for(&mut world, |world| world.get_chunk_data(-1, -1).is_some()).await;
+    assert_eq!(
+        world.get_block(-1, 64, -1),
+        Some(Block::GrassBlock { snowy: false })
+    );
+    assert!(world.set_block(-1, 64, -1, Block::Air));
+    assert_eq!(world.get_block(-1, 64, -1), Some(Block::Air));
+    assert!(!world.set_block(0, 320, 0, Block::Stone));
+    assert_eq!(world.get_block(0, -65, 0), None);
+
+    assert!(Block::Air.is_replaceable());
+    assert!(!Block::Stone.is_replaceable());
+    assert_eq!(Block::Bedrock.hardness(), None);
+    assert_eq!(Block::Torch.hardness(), Some(0.0));
+    assert_eq!(Block::Stone.hardness(), Some(1.5));
+    assert!(!Block::Torch.blocks_motion());
+    assert_eq!(Block::Glowstone.light_emission(), 15);
+    assert_eq!(Block::Glass.light_opacity(), 0);
+    assert_eq!(
+        Block::from_name("oak_leaves").unwrap(),
+        Block::OakLeaves {
+            distance: I1_7::new(7).unwrap(),
+            persistent: false,
+            waterlogged: false,
+        }
+    );
+    assert_eq!(
+        Block::from_name("redstone_torch").unwrap().light_emission(),
+        7
+    );
+}
+
+#[test]
+fn test_paletted_container() {
+    let mut blocks: PalettedContainer = PalettedContainer::new(Block::Air);
+    let mut bytes: Vec<u8> = vec![];
+    blocks.write_to(&mut bytes);
+    assert_eq!(bytes, [0, 0, 0]);
+
+    // Grows from a single value through every palette size to state ids, past 256 entries
+    let mut expected: Vec<Block> = vec![Block::Air; 4096];
+    for i in 0..300 {
+        let block: Block = Block::from_state_id(i as u16 * 7 + 1).unwrap();
+        assert_eq!(blocks.set(i * 13, block), Block::Air);
+        expected[i * 13] = block;
+        if i == 15 || i == 254 {
+            assert_eq!(blocks, PalettedContainer::from_blocks(&expected));
+            let mut bytes: Vec<u8> = vec![];
+            blocks.write_to(&mut bytes);
+            assert_eq!(bytes[0], if i == 15 { 5 } else { 8 });
+        }
+    }
+    assert_eq!(blocks, PalettedContainer::from_blocks(&expected));
+    assert_eq!(blocks.non_air_count(), 300);
+    let mut bytes: Vec<u8> = vec![];
+    blocks.write_to(&mut bytes);
+    assert_eq!(bytes[0], 15);
+
+    assert_eq!(blocks.set(0, Block::Air), expected[0]);
+    assert_eq!(blocks.non_air_count(), 299);
+    assert!(blocks
+        .iter()
+        .skip(1)
+        .eq(expected[1..].iter().map(Block::get_state_id)));
+
+    let section: ChunkSection = ChunkSection::new(vec![Block::Stone; 4096], Biome::Plains);
+    assert_eq!(section.to_bytes()[..5], [0x10, 0x00, 0, 1, 0]);
+}